@@ -0,0 +1,355 @@
+use crate::*;
+
+pub const SIGHT_RANGE: f32 = 300.0; // how far away an actor can notice the player from
+pub const ATTACK_RANGE: f32 = 60.0; // how close an actor has to be to attack
+pub const ATTACK_TICKS: u32 = 20; // how long an attack takes; it lands halfway through
+pub const PAIN_TICKS: u32 = 10; // how long an actor flinches for after being hit
+pub const REPATH_TICKS: u32 = 30; // how often a chasing actor works out a new path
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActorState {
+    Idle,   // waiting to see the player
+    Chase,  // following a path towards the player
+    Attack, // winding up a hit on the player
+    Pain,   // flinching after being shot
+    Death,  // out of health; stays where it fell
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Actor {
+    pub entity: usize, // the entity this actor was spawned from
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub angle: i32,
+    pub health: i32,
+    pub damage: i32, // how much health an attack takes from the player
+    pub speed: f32,  // how far the actor moves each tick
+    pub height: f32,
+    pub texture: Texture,
+    pub state: ActorState,
+    pub ticks: u32,            // how long the actor has been in its current state
+    pub path: Vec<(f32, f32)>, // the points the actor is walking through to reach the player
+}
+
+impl Actor {
+    pub fn spawn(e: usize, entity: &Entity) -> Option<Actor> {
+        let texture = match (entity.kind, entity.texture()) {
+            (EntityKind::Enemy, Some(texture)) => texture,
+            _ => return None,
+        };
+        let number = |key: &str, default: f32| match entity.property(key).map(|v| v.parse()) {
+            Some(Ok(value)) => value,
+            _ => default,
+        }; // properties set in the editor override the defaults
+        Some(Actor {
+            entity: e,
+            x: entity.x,
+            y: entity.y,
+            z: entity.z,
+            angle: entity.angle,
+            health: number("health", 30.0) as i32,
+            damage: number("damage", 10.0) as i32,
+            speed: number("speed", 1.5),
+            height: entity.height(),
+            texture,
+            state: ActorState::Idle,
+            ticks: 0,
+            path: Vec::new(),
+        })
+    } // creates an actor for an enemy entity
+
+    pub fn alive(&self) -> bool {
+        self.state != ActorState::Death
+    }
+
+    pub fn sprite(&self) -> Sprite {
+        let height = match self.state {
+            ActorState::Death => self.height / 4.0, // squashed flat
+            ActorState::Pain => self.height * 0.9,  // flinching
+            _ => self.height,
+        };
+        Sprite {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            height,
+            texture: self.texture,
+        }
+    } // how the actor looks in its current state
+
+    pub fn damage(&mut self, damage: i32) {
+        if !self.alive() {
+            return;
+        }
+        self.health -= damage;
+        self.state = match self.health > 0 {
+            true => ActorState::Pain,
+            false => ActorState::Death,
+        };
+        self.ticks = 0;
+    } // takes health away from the actor when it is shot
+
+    fn enter(&mut self, state: ActorState) {
+        self.state = state;
+        self.ticks = 0;
+    }
+}
+
+impl Level {
+    pub fn line_of_sight(&self, x1: f32, y1: f32, x2: f32, y2: f32, z: f32) -> bool {
+        for (w, wall) in self.walls.iter().enumerate() {
+            let solid = match self.sector_of_wall(w) {
+                Some(s) => self.sectors[s].blocks(z as i32),
+                _ => true,
+            } && !wall.see_through(); // actors can see through grates & windows
+            if solid && wall.crossed_by(x1, y1, x2, y2).is_some() {
+                return false;
+            }
+        }
+        true
+    } // whether nothing solid is between two points at a height
+
+    pub fn center(&self, s: usize) -> (f32, f32) {
+        let walls =
+            &self.walls[self.sectors[s].wall_start as usize..self.sectors[s].wall_end as usize];
+        let count = one_if_none(walls.len() as f32);
+        (
+            walls.iter().map(|wall| wall.x1).sum::<f32>() / count,
+            walls.iter().map(|wall| wall.y1).sum::<f32>() / count,
+        )
+    } // the average of a sector's points
+
+    pub fn neighbours(&self, node: usize) -> Vec<(usize, (f32, f32))> {
+        let outside = self.sectors.len(); // the open space between sectors is one more node
+        let mut neighbours = Vec::new();
+        for (s, sector) in self.sectors.iter().enumerate() {
+            for w in sector.wall_start as usize..sector.wall_end as usize {
+                let wall = self.walls[w];
+                let other = match self.neighbour_of_wall(w) {
+                    Some(other) => other,
+                    _ => outside,
+                };
+                let portal = ((wall.x1 + wall.x2) / 2.0, (wall.y1 + wall.y2) / 2.0);
+                if s == node && other != node {
+                    neighbours.push((other, portal));
+                } else if other == node && s != node {
+                    neighbours.push((s, portal));
+                }
+            }
+        }
+        neighbours
+    } // the sectors next to a sector (or to the open space) and the middle of the wall between them
+
+    pub fn find_path(&self, from: (f32, f32), to: (f32, f32), z: f32) -> Option<Vec<(f32, f32)>> {
+        let outside = self.sectors.len();
+        let node_of = |(x, y): (f32, f32)| self.sector_at(x, y).unwrap_or(outside);
+        let (start, goal) = (node_of(from), node_of(to));
+        if start == goal {
+            return Some(vec![to]);
+        } // already in the same place
+
+        let position = |node: usize, entered: (f32, f32)| match node == outside {
+            true => entered,
+            false => self.center(node),
+        }; // the open space has no middle so it is measured from where it was entered
+        let mut open: Vec<(usize, f32, (f32, f32))> = vec![(start, 0.0, from)];
+        let mut came_from: Vec<Option<(usize, (f32, f32))>> = vec![None; outside + 1];
+        let mut cost = vec![f32::INFINITY; outside + 1];
+        cost[start] = 0.0;
+        while !open.is_empty() {
+            let best = (0..open.len())
+                .min_by(|a, b| {
+                    let score = |(node, g, entered): (usize, f32, (f32, f32))| {
+                        let (x, y) = position(node, entered);
+                        g + distance(x, y, to.0, to.1)
+                    }; // cost so far plus a straight line to the goal
+                    score(open[*a]).total_cmp(&score(open[*b]))
+                })
+                .unwrap_or(0);
+            let (node, g, entered) = open.remove(best);
+            if node == goal {
+                let mut path = vec![to];
+                let mut current = node;
+                while let Some((previous, portal)) = came_from[current] {
+                    path.push(portal);
+                    current = previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let (x, y) = position(node, entered);
+            for (next, portal) in self.neighbours(node) {
+                if next != outside && next != goal && self.sectors[next].blocks(z as i32) {
+                    continue;
+                } // can't walk through solid sectors
+                let next_cost = g + distance(x, y, portal.0, portal.1);
+                if next_cost < cost[next] {
+                    cost[next] = next_cost;
+                    came_from[next] = Some((node, portal));
+                    open.retain(|(open_node, _, _)| *open_node != next);
+                    open.push((next, next_cost, portal));
+                }
+            }
+        }
+        None
+    } // A* over the sectors; the points to walk through to get from one place to another, if it can be reached
+}
+
+impl PlayerInfo {
+    pub fn update_actors(&mut self) {
+        let (player_x, player_y, player_z) = (
+            self.position.x as f32,
+            self.position.y as f32,
+            self.position.z as f32,
+        );
+        for a in 0..self.level.actors.len() {
+            let mut actor = self.level.actors[a].clone();
+            actor.ticks += 1;
+            let player_distance = distance(actor.x, actor.y, player_x, player_y);
+            let sees_player = player_distance <= SIGHT_RANGE
+                && self
+                    .level
+                    .line_of_sight(actor.x, actor.y, player_x, player_y, player_z);
+            match actor.state {
+                ActorState::Idle => {
+                    if sees_player {
+                        actor.enter(ActorState::Chase);
+                    }
+                }
+                ActorState::Chase => {
+                    if sees_player && player_distance <= ATTACK_RANGE {
+                        actor.enter(ActorState::Attack);
+                    } else {
+                        if actor.path.is_empty() || actor.ticks.is_multiple_of(REPATH_TICKS) {
+                            actor.path = self
+                                .level
+                                .find_path((actor.x, actor.y), (player_x, player_y), actor.z)
+                                .unwrap_or(vec![(player_x, player_y)]); // with no way around, head straight for the player
+                        }
+                        self.level.walk_actor(&mut actor);
+                    }
+                }
+                ActorState::Attack => {
+                    if actor.ticks == ATTACK_TICKS / 2
+                        && sees_player
+                        && player_distance <= ATTACK_RANGE
+                    {
+                        self.health -= actor.damage;
+                    } // the attack misses if the player got away in time
+                    if actor.ticks >= ATTACK_TICKS {
+                        actor.enter(ActorState::Chase);
+                    }
+                }
+                ActorState::Pain => {
+                    if actor.ticks >= PAIN_TICKS {
+                        actor.enter(ActorState::Chase);
+                    }
+                }
+                ActorState::Death => {}
+            }
+            if actor.alive() && actor.state != ActorState::Idle {
+                actor.angle = (player_x - actor.x).atan2(player_y - actor.y).to_degrees() as i32;
+            } // awake actors face the player
+            self.level.actors[a] = actor;
+        }
+
+        if self.health <= 0 {
+            self.level.died = true;
+            self.health = PLAYER_HEALTH;
+            self.weapons = weapons::Weapons::new();
+            self.level.spawn(); // the enemies come back too
+            self.start();
+        } // back to the player start
+    } // runs every actor's state machine one tick
+}
+
+impl Level {
+    fn walk_actor(&self, actor: &mut Actor) {
+        let (target_x, target_y) = match actor.path.first() {
+            Some(point) => *point,
+            _ => return,
+        };
+        let remaining = distance(actor.x, actor.y, target_x, target_y);
+        if remaining <= actor.speed {
+            actor.path.remove(0);
+        } // reached this point on the path, on to the next
+        let step = actor.speed.min(remaining) / one_if_none(remaining);
+        let (x, y) = (
+            actor.x + (target_x - actor.x) * step,
+            actor.y + (target_y - actor.y) * step,
+        );
+        match self.sector_at(x, y) {
+            Some(s) if self.sectors[s].blocks(actor.z as i32) => actor.path = Vec::new(),
+            _ => {
+                actor.x = x;
+                actor.y = y;
+            }
+        } // an actor that walks into something solid works out a new path
+    } // moves an actor along its path
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{level, square};
+
+    #[test]
+    fn path_goes_through_the_shared_wall() {
+        let level = level(&[
+            (square(0.0, 0.0, 10.0), 60, 100),
+            (square(10.0, 0.0, 10.0), 60, 100),
+        ]);
+        let path = level.find_path((5.0, 5.0), (15.0, 5.0), 50.0);
+        assert_eq!(path, Some(vec![(10.0, 5.0), (15.0, 5.0)]));
+    }
+
+    #[test]
+    fn path_goes_around_through_the_outside() {
+        let level = level(&[
+            (square(0.0, 0.0, 10.0), 60, 100),
+            (square(30.0, 0.0, 10.0), 60, 100),
+        ]);
+        let path = level.find_path((5.0, 5.0), (35.0, 5.0), 50.0).unwrap();
+        assert_eq!(path.len(), 3); // out of one sector, across the open space & into the other
+        assert_eq!(path.last(), Some(&(35.0, 5.0)));
+        assert!(path[0].0 <= 10.0 && path[1].0 >= 30.0); // leaves by a wall of the first, enters by a wall of the second
+    }
+
+    #[test]
+    fn path_starts_outside_every_sector() {
+        let level = level(&[(square(0.0, 0.0, 10.0), 60, 100)]);
+        let path = level.find_path((50.0, 50.0), (5.0, 5.0), 50.0).unwrap();
+        assert_eq!(path.len(), 2);
+        assert_eq!(path.last(), Some(&(5.0, 5.0)));
+    }
+
+    #[test]
+    fn path_to_a_walled_in_sector_is_none() {
+        let level = level(&[
+            (square(10.0, 10.0, 10.0), 60, 100), // the goal
+            (square(0.0, 10.0, 10.0), 0, 100),   // solid on every side of it
+            (square(20.0, 10.0, 10.0), 0, 100),
+            (square(10.0, 0.0, 10.0), 0, 100),
+            (square(10.0, 20.0, 10.0), 0, 100),
+        ]);
+        assert_eq!(level.find_path((50.0, 50.0), (15.0, 15.0), 50.0), None);
+        assert!(level.find_path((50.0, 50.0), (15.0, 15.0), 110.0).is_some()); // above the walls
+    }
+
+    #[test]
+    fn solid_walls_block_sight() {
+        let level = level(&[(square(10.0, -10.0, 20.0), 0, 100)]);
+        assert!(!level.line_of_sight(0.0, 0.0, 40.0, 0.0, 50.0));
+        assert!(level.line_of_sight(0.0, 0.0, 40.0, 0.0, 150.0)); // over the top
+        assert!(level.line_of_sight(0.0, 20.0, 40.0, 20.0, 50.0)); // beside it
+    }
+
+    #[test]
+    fn masked_walls_do_not_block_sight() {
+        let mut level = level(&[(square(10.0, -10.0, 20.0), 0, 100)]);
+        for wall in level.walls.iter_mut() {
+            wall.masked = true;
+        }
+        assert!(level.line_of_sight(0.0, 0.0, 40.0, 0.0, 50.0));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::*;
+use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
+use std::f32::consts::PI;
+
+pub const FIRE: u32 = 0; // sounds are numbered so triggers can ask for them
+pub const DOOR: u32 = 1;
+pub const FOOTSTEP: u32 = 2;
+pub const SPLASH: u32 = 3;
+pub const PAIN: u32 = 4;
+pub const SOUND_NAMES: [&str; 5] = ["Fire", "Door", "Footstep", "Splash", "Pain"];
+
+pub const SAMPLE_RATE: i32 = 22050;
+pub const HEARING_RANGE: f32 = 600.0; // sounds further away than this can't be heard
+pub const SOUND_VOLUME: f32 = 0.6;
+pub const MUSIC_VOLUME: f32 = 0.15;
+pub const FOOTSTEP_TICKS: u32 = 12; // how many steps of bob between footsteps
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundEvent {
+    pub sound: u32,
+    pub x: f32, // where in the level the sound came from
+    pub y: f32,
+}
+
+struct Voice {
+    samples: Arc<Vec<f32>>,
+    position: usize,
+    left: f32, // how loud the sound is in each ear
+    right: f32,
+}
+
+pub struct Mixer {
+    voices: Vec<Voice>,
+    music: Option<Arc<Vec<f32>>>,
+    music_position: usize,
+}
+
+impl AudioCallback for Mixer {
+    type Channel = f32;
+
+    fn callback(&mut self, out: &mut [f32]) {
+        for frame in out.chunks_mut(2) {
+            let (mut left, mut right) = (0.0, 0.0);
+            for voice in self.voices.iter_mut() {
+                match voice.samples.get(voice.position) {
+                    Some(sample) => {
+                        left += sample * voice.left;
+                        right += sample * voice.right;
+                        voice.position += 1;
+                    }
+                    _ => {}
+                }
+            }
+            match &self.music {
+                Some(music) if !music.is_empty() => {
+                    let sample = music[self.music_position % music.len()] * MUSIC_VOLUME;
+                    left += sample;
+                    right += sample;
+                    self.music_position = (self.music_position + 1) % music.len();
+                }
+                _ => {}
+            } // the music loops forever
+            frame[0] = left.clamp(-1.0, 1.0);
+            if frame.len() > 1 {
+                frame[1] = right.clamp(-1.0, 1.0);
+            }
+        }
+        self.voices
+            .retain(|voice| voice.position < voice.samples.len());
+    } // SDL calls this from its own thread whenever it needs more samples
+}
+
+pub struct Audio {
+    device: Option<AudioDevice<Mixer>>, // None when there's no audio device, so the game stays silent
+    sounds: Vec<Arc<Vec<f32>>>,
+}
+
+impl Audio {
+    pub fn new(sdl_context: &sdl2::Sdl) -> Audio {
+        let device = sdl_context.audio().and_then(|audio| {
+            audio.open_playback(
+                None,
+                &AudioSpecDesired {
+                    freq: Some(SAMPLE_RATE),
+                    channels: Some(2),
+                    samples: Some(512),
+                },
+                |_| Mixer {
+                    voices: Vec::new(),
+                    music: None,
+                    music_position: 0,
+                },
+            )
+        }); // SDL_AUDIODRIVER=dummy opens a device that plays nothing, for machines without sound
+        let device = match device {
+            Ok(device) => {
+                device.resume();
+                Some(device)
+            }
+            Err(e) => {
+                eprintln!("no audio: {}", e);
+                None
+            }
+        };
+        let rate = match &device {
+            Some(device) => device.spec().freq,
+            _ => SAMPLE_RATE,
+        };
+        Audio {
+            device,
+            sounds: vec![
+                fire(rate),
+                door(rate),
+                footstep(rate),
+                splash(rate),
+                pain(rate),
+            ],
+        }
+    }
+
+    pub fn play(&mut self, event: SoundEvent, player: &PlayerInfo) {
+        let x = event.x - player.position.x as f32;
+        let y = event.y - player.position.y as f32;
+        let across = x * cosine(player.angle_h) - y * sine(player.angle_h); // the same turn draw3d uses
+        let distance = distance(0.0, 0.0, x, y);
+        let volume = (1.0 - distance / HEARING_RANGE).max(0.0) * SOUND_VOLUME;
+        if volume <= 0.0 {
+            return;
+        }
+        let pan = (across / one_if_none(distance)).clamp(-1.0, 1.0); // -1 is all the way left
+        let angle = (pan + 1.0) * PI / 4.0; // keeps the sound as loud overall wherever it is panned
+        let samples = match self.sounds.get(event.sound as usize) {
+            Some(samples) => samples.clone(),
+            _ => return,
+        };
+        match &mut self.device {
+            Some(device) => device.lock().voices.push(Voice {
+                samples,
+                position: 0,
+                left: angle.cos() * volume,
+                right: angle.sin() * volume,
+            }),
+            _ => {}
+        }
+    } // starts a sound, quieter the further it is from the player & panned to the side it is on
+
+    pub fn play_music(&mut self) {
+        let rate = match &self.device {
+            Some(device) => device.spec().freq,
+            _ => return,
+        };
+        match &mut self.device {
+            Some(device) => {
+                let mut mixer = device.lock();
+                mixer.music = Some(music(rate));
+                mixer.music_position = 0;
+            }
+            _ => {}
+        }
+    } // starts the level's music from the beginning
+}
+
+impl Level {
+    pub fn sound(&mut self, sound: u32, x: f32, y: f32) {
+        self.sounds.push(SoundEvent { sound, x, y });
+    } // asks for a sound to be played at a place in the level
+}
+
+fn synth(rate: i32, seconds: f32, sample: impl Fn(f32) -> f32) -> Arc<Vec<f32>> {
+    Arc::new(
+        (0..(rate as f32 * seconds) as usize)
+            .map(|i| sample(i as f32 / rate as f32))
+            .collect(),
+    )
+} // builds a sound from what it should be at every moment
+
+fn noise(t: f32) -> f32 {
+    let n = ((t * 44100.0) as u32)
+        .wrapping_mul(1103515245)
+        .wrapping_add(12345);
+    ((n >> 16) & 0x7fff) as f32 / 16384.0 - 1.0
+} // the same hiss every time a sound is built
+
+fn fire(rate: i32) -> Arc<Vec<f32>> {
+    synth(rate, 0.25, |t| {
+        noise(t) * (-t * 18.0).exp() * 0.6
+            + (2.0 * PI * (600.0 - t * 1600.0) * t).sin() * (-t * 30.0).exp() * 0.4
+    })
+} // a squirt of water with a quick falling chirp
+
+fn door(rate: i32) -> Arc<Vec<f32>> {
+    synth(rate, 1.0, |t| {
+        let envelope = (t * 10.0).min(1.0) * (1.0 - t).max(0.0);
+        ((2.0 * PI * 55.0 * t).sin().signum() * 0.4 + noise(t) * 0.3) * envelope
+    })
+} // a low grinding rumble
+
+fn footstep(rate: i32) -> Arc<Vec<f32>> {
+    synth(rate, 0.12, |t| {
+        (2.0 * PI * 80.0 * t).sin() * (-t * 40.0).exp() + noise(t) * (-t * 60.0).exp() * 0.3
+    })
+} // a short thump
+
+fn splash(rate: i32) -> Arc<Vec<f32>> {
+    synth(rate, 0.2, |t| {
+        noise(t) * (-t * 25.0).exp() * (2.0 * PI * 30.0 * t).sin().abs()
+    })
+} // a wet patter
+
+fn pain(rate: i32) -> Arc<Vec<f32>> {
+    synth(rate, 0.25, |t| {
+        (2.0 * PI * (900.0 - t * 2000.0) * t).sin() * (1.0 - t * 4.0).max(0.0) * 0.5
+    })
+} // a falling squeak
+
+fn music(rate: i32) -> Arc<Vec<f32>> {
+    let chords: [[f32; 3]; 4] = [
+        [220.00, 261.63, 329.63], // A minor
+        [174.61, 220.00, 261.63], // F
+        [261.63, 329.63, 392.00], // C
+        [196.00, 246.94, 293.66], // G
+    ];
+    let note = 0.25; // how long each note of the arpeggio lasts
+    synth(rate, note * 32.0, |t| {
+        let step = (t / note) as usize;
+        let chord = chords[step / 8 % chords.len()];
+        let pitch = chord[[0, 1, 2, 1][step % 4]];
+        let time_in_note = t % note;
+        let lead = (2.0 * PI * pitch * t).sin() * (-time_in_note * 6.0).exp();
+        let bass = (2.0 * PI * chord[0] / 2.0 * t).sin() * 0.5;
+        (lead + bass) * 0.6
+    })
+} // a looping arpeggio over four chords
@@ -0,0 +1,86 @@
+use crate::renderer::Renderer;
+use crate::*;
+
+pub const AUTOMAP_MIN_SCALE: f32 = 0.25;
+pub const AUTOMAP_MAX_SCALE: f32 = 8.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Automap {
+    pub open: bool,
+    pub rotate: bool, // turns with the player so forward is always up, otherwise +y is up
+    pub scale: f32,   // how many pixels a unit of the level takes up
+    pub seen: HashSet<usize>, // walls that have been drawn in 3D; only these are shown
+}
+
+impl Default for Automap {
+    fn default() -> Automap {
+        Automap::new()
+    }
+}
+
+impl Automap {
+    pub fn new() -> Automap {
+        Automap {
+            open: false,
+            rotate: true,
+            scale: 2.0,
+            seen: HashSet::new(),
+        }
+    }
+
+    pub fn zoom(&mut self, amount: f32) {
+        self.scale = (self.scale * amount).clamp(AUTOMAP_MIN_SCALE, AUTOMAP_MAX_SCALE);
+    }
+
+    fn screen_point(&self, player: &PlayerInfo, x: f32, y: f32) -> (f32, f32) {
+        let x = x - player.position.x as f32;
+        let y = y - player.position.y as f32;
+        let (x, y) = match self.rotate {
+            true => (
+                x * cosine(player.angle_h) - y * sine(player.angle_h),
+                y * cosine(player.angle_h) + x * sine(player.angle_h),
+            ), // the same turn draw3d uses, so left & right match the view
+            false => (x, y),
+        };
+        (
+            HALF_WIDTH as f32 + x * self.scale,
+            HALF_HEIGHT as f32 - y * self.scale,
+        )
+    } // converts a point in the level to the screen with the player in the middle
+}
+
+impl Renderer {
+    pub fn draw_automap(&mut self, player: &PlayerInfo) -> Result<(), String> {
+        if !self.automap.open {
+            return Ok(());
+        }
+        self.canvas.set_blend_mode(BlendMode::Blend);
+        self.canvas.set_draw_color(Color::RGBA(0, 0, 0, 160));
+        self.canvas
+            .fill_rect(Rect::new(0, 0, SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32))?;
+        self.canvas.set_blend_mode(BlendMode::None);
+
+        for w in self.automap.seen.clone() {
+            let wall = match player.level.walls.get(w) {
+                Some(wall) => *wall,
+                _ => continue,
+            }; // walls deleted in the editor since they were seen
+            let (x1, y1) = self.automap.screen_point(player, wall.x1, wall.y1);
+            let (x2, y2) = self.automap.screen_point(player, wall.x2, wall.y2);
+            self.canvas.set_draw_color(wall.color);
+            self.canvas
+                .draw_line((x1 as i32, y1 as i32), (x2 as i32, y2 as i32))?;
+        }
+
+        let facing = match self.automap.rotate {
+            true => 0,
+            false => player.angle_h,
+        };
+        let (x, y) = (HALF_WIDTH as f32, HALF_HEIGHT as f32);
+        self.draw_dot(x - 1.0, y - 1.0, colors::WHITE)?;
+        for t in facing - 22..facing + 22 {
+            self.draw_dot(x + 16.0 * sine(t), y - 16.0 * cosine(t), colors::WHITE)?;
+        } // an arc in the direction the player is facing, like the one in the editor
+        Ok(())
+    } // draws the walls the player has seen from above over the 3D view
+}
@@ -198,3 +198,39 @@ pub const BLACK: Color = Color {
     b: 0,
     a: 255,
 };
+
+pub const PALETTE: [Color; 33] = [
+    LIGHT_RED,
+    RED,
+    DARK_RED,
+    LIGHT_ORANGE,
+    ORANGE,
+    DARK_ORANGE,
+    LIGHT_YELLOW,
+    YELLOW,
+    DARK_YELLOW,
+    LIGHT_GREEN,
+    GREEN,
+    DARK_GREEN,
+    LIGHT_BLUE,
+    BLUE,
+    DARK_BLUE,
+    LIGHT_PURPLE,
+    PURPLE,
+    DARK_PURPLE,
+    LIGHT_CYAN,
+    CYAN,
+    DARK_CYAN,
+    LIGHT_PINK,
+    PINK,
+    DARK_PINK,
+    LIGHT_BROWN,
+    BROWN,
+    DARK_BROWN,
+    WHITE,
+    GREY1,
+    GREY2,
+    GREY3,
+    GREY4,
+    BLACK,
+]; // every color above in order; used by the color pickers in the editor
@@ -0,0 +1,402 @@
+use crate::renderer::Renderer;
+use crate::*;
+
+pub const CONFIG_PATH: &str = "timaeus.cfg"; // where cvars are kept between runs
+pub const CONSOLE_HEIGHT: u32 = (SCREEN_HEIGHT / 2) as u32; // how far down the console drops
+pub const CONSOLE_LINE_HEIGHT: u32 = (SCREEN_HEIGHT / 40) as u32;
+pub const CONSOLE_SCROLLBACK: usize = 200; // how many lines of output are kept
+pub const LEVELS: [(&str, Load); 2] = [("level", Level::load), ("empty", Level::default)]; // what load can load, by name
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cvar {
+    pub name: &'static str,
+    pub value: String,
+    pub help: &'static str,
+}
+
+pub type Run = fn(&mut PlayerInfo, &mut Renderer, &[&str]) -> Result<String, String>;
+pub type Load = fn() -> Level;
+
+#[derive(Clone)]
+pub struct Command {
+    pub name: &'static str,
+    pub usage: &'static str, // shown by help
+    pub run: Run,
+}
+
+#[derive(Clone)]
+pub struct Console {
+    pub open: bool,
+    pub input: String,          // the line being typed
+    pub lines: Vec<String>,     // everything that has been typed & printed
+    pub history: Vec<String>,   // lines that have been run, for up & down to go through
+    pub history_index: usize,   // which line of history is being shown
+    pub cvars: Vec<Cvar>,       // settings that can be changed while playing and are saved
+    pub commands: Vec<Command>, // everything that can be typed besides cvars
+}
+
+impl Default for Console {
+    fn default() -> Console {
+        Console::new()
+    }
+}
+
+impl Console {
+    pub fn new() -> Console {
+        let mut console = Console {
+            open: false,
+            input: String::new(),
+            lines: Vec::new(),
+            history: Vec::new(),
+            history_index: 0,
+            cvars: Vec::new(),
+            commands: Vec::new(),
+        };
+        console.register_cvar(
+            "fov",
+            &format!(
+                "{:.0}",
+                (HALF_WIDTH as f32 / renderer::FOV).atan().to_degrees() * 2.0
+            ),
+            "horizontal field of view in degrees",
+        );
+        console.register_cvar("r_wireframe", "0", "1 draws only the outlines of walls");
+        console.register_cvar("r_mipmap", "1", "1 draws far walls from smaller textures");
+        console.register_cvar("r_bilinear", "0", "1 smooths the textures of close walls");
+        console.register_cvar("hud_fps", "0", "1 shows the frame rate on the HUD");
+        console.register_command("noclip", "noclip - walk through sectors", noclip);
+        console.register_command("teleport", "teleport x y z - moves the player", teleport);
+        console.register_command("load", "load <level> - loads level or empty", load);
+        console.register_command("save", "save - writes the level to level.rs", save);
+        console
+    }
+
+    pub fn register_cvar(&mut self, name: &'static str, value: &str, help: &'static str) {
+        self.cvars.push(Cvar {
+            name,
+            value: value.to_string(),
+            help,
+        });
+    } // adds a setting with its default value
+
+    pub fn register_command(&mut self, name: &'static str, usage: &'static str, run: Run) {
+        self.commands.push(Command { name, usage, run });
+    } // adds a command that can be typed into the console
+
+    pub fn cvar(&self, name: &str) -> Option<&str> {
+        self.cvars
+            .iter()
+            .find(|cvar| cvar.name == name)
+            .map(|cvar| cvar.value.as_str())
+    }
+
+    pub fn cvar_number(&self, name: &str) -> f32 {
+        match self.cvar(name).map(|value| value.parse()) {
+            Some(Ok(number)) => number,
+            _ => 0.0,
+        }
+    } // cvars that aren't numbers count as 0
+
+    pub fn print(&mut self, line: &str) {
+        for line in line.lines() {
+            self.lines.push(line.to_string());
+        }
+        if self.lines.len() > CONSOLE_SCROLLBACK {
+            self.lines.drain(..self.lines.len() - CONSOLE_SCROLLBACK);
+        }
+    } // adds output to the bottom of the console
+
+    pub fn load_config(&mut self, path: &str) -> Result<(), String> {
+        let mut file = File::open(path).map_err(|e| e.to_string())?;
+        let mut config = String::new();
+        file.read_to_string(&mut config)
+            .map_err(|e| e.to_string())?;
+        for line in config.lines() {
+            match parse(line) {
+                Some((name, arguments)) if !arguments.is_empty() => {
+                    let value = arguments[0];
+                    match self.cvars.iter_mut().find(|cvar| cvar.name == name) {
+                        Some(cvar) => cvar.value = value.to_string(),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    } // reads the cvars saved last time; anything missing keeps its default
+
+    pub fn save_config(&self, path: &str) -> Result<(), String> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)
+            .map_err(|e| e.to_string())?;
+        for cvar in self.cvars.iter() {
+            writeln!(file, "{} {}", cvar.name, cvar.value).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    } // writes every cvar out so they are the same next time
+
+    pub fn apply(&self, renderer: &mut Renderer) {
+        let fov = self.cvar_number("fov").clamp(10.0, 170.0);
+        renderer.focal = HALF_WIDTH as f32 / (fov.to_radians() / 2.0).tan();
+        renderer.wireframe = self.cvar_number("r_wireframe") != 0.0;
+        renderer.mipmapping = self.cvar_number("r_mipmap") != 0.0;
+        renderer.bilinear = self.cvar_number("r_bilinear") != 0.0;
+        renderer.hud.show_fps = self.cvar_number("hud_fps") != 0.0;
+    } // makes the game match the cvars
+
+    pub fn execute(&mut self, line: &str, player: &mut PlayerInfo, renderer: &mut Renderer) {
+        self.print(&format!("> {}", line));
+        let (name, arguments) = match parse(line) {
+            Some(words) => words,
+            _ => return,
+        };
+
+        match self.cvars.iter().position(|cvar| cvar.name == name) {
+            Some(c) => {
+                match arguments.first() {
+                    Some(value) => {
+                        self.cvars[c].value = value.to_string();
+                        self.apply(renderer);
+                        match self.save_config(CONFIG_PATH) {
+                            Ok(()) => {}
+                            Err(e) => self.print(&format!("couldn't save {}: {}", CONFIG_PATH, e)),
+                        }
+                    }
+                    _ => {
+                        let cvar = self.cvars[c].clone();
+                        self.print(&format!("{} is {} - {}", cvar.name, cvar.value, cvar.help));
+                    }
+                }
+                return;
+            }
+            _ => {}
+        } // typing a cvar's name shows it & typing a value after it sets it
+
+        let output = match name {
+            "help" => {
+                let mut output = String::from("commands:\n  help - lists every command & cvar");
+                for command in self.commands.iter() {
+                    output.push_str(&format!("\n  {}", command.usage));
+                }
+                output.push_str("\ncvars:");
+                for cvar in self.cvars.iter() {
+                    output.push_str(&format!("\n  {} {} - {}", cvar.name, cvar.value, cvar.help));
+                }
+                Ok(output)
+            } // help needs to see the registry so it is handled here
+            _ => match self.commands.iter().find(|command| command.name == name) {
+                Some(command) => (command.run)(player, renderer, &arguments),
+                _ => Err(format!("unknown command {}", name)),
+            },
+        };
+        match output {
+            Ok(output) => self.print(&output),
+            Err(e) => self.print(&format!("error: {}", e)),
+        }
+    } // runs a line typed into the console
+
+    pub fn key(&mut self, keycode: Keycode, player: &mut PlayerInfo, renderer: &mut Renderer) {
+        match keycode {
+            Keycode::Return | Keycode::KpEnter => {
+                let line = std::mem::take(&mut self.input);
+                if !line.trim().is_empty() {
+                    self.history.push(line.clone());
+                }
+                self.history_index = self.history.len();
+                self.execute(&line, player, renderer);
+            }
+            Keycode::Backspace => {
+                self.input.pop();
+            }
+            Keycode::Up => {
+                self.history_index = self.history_index.saturating_sub(1);
+                match self.history.get(self.history_index) {
+                    Some(line) => self.input = line.clone(),
+                    _ => {}
+                }
+            }
+            Keycode::Down => {
+                self.history_index = (self.history_index + 1).min(self.history.len());
+                self.input = match self.history.get(self.history_index) {
+                    Some(line) => line.clone(),
+                    _ => String::new(),
+                };
+            }
+            Keycode::Escape => self.open = false,
+            _ => {}
+        }
+    } // handles the keys that edit the line being typed
+
+    pub fn type_text(&mut self, text: &str) {
+        self.input.extend(text.chars().filter(|c| *c != '`'));
+    } // the key that opens the console isn't typed into it
+}
+
+pub fn parse(line: &str) -> Option<(&str, Vec<&str>)> {
+    let mut words = line.split_whitespace();
+    words.next().map(|name| (name, words.collect()))
+} // splits a line into the command or cvar name & what follows it; blank lines are None
+
+fn noclip(player: &mut PlayerInfo, _: &mut Renderer, _: &[&str]) -> Result<String, String> {
+    player.noclip = !player.noclip;
+    Ok(format!(
+        "noclip {}",
+        match player.noclip {
+            true => "on",
+            false => "off",
+        }
+    ))
+}
+
+fn teleport(
+    player: &mut PlayerInfo,
+    _: &mut Renderer,
+    arguments: &[&str],
+) -> Result<String, String> {
+    let numbers: Vec<i32> = arguments
+        .iter()
+        .map(|argument| argument.parse::<f32>().map(|n| n as i32))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.to_string())?;
+    match numbers[..] {
+        [x, y] => {
+            player.position = XYZ {
+                x,
+                y,
+                z: player.position.z,
+            }
+        }
+        [x, y, z] => player.position = XYZ { x, y, z },
+        _ => return Err("usage: teleport x y z".to_string()),
+    }
+    Ok(format!("teleported to {:?}", player.position))
+}
+
+fn load(
+    player: &mut PlayerInfo,
+    renderer: &mut Renderer,
+    arguments: &[&str],
+) -> Result<String, String> {
+    let name = arguments.first().copied().unwrap_or("level");
+    match LEVELS.iter().find(|(level, _)| *level == name) {
+        Some((_, level)) => {
+            player.level = level();
+            renderer.automap.seen.clear(); // the new level hasn't been seen yet
+            player.start();
+            Ok(format!("loaded {}", name))
+        }
+        _ => Err(format!(
+            "no level called {}; levels are {}",
+            name,
+            LEVELS.map(|(level, _)| level).join(", ")
+        )),
+    }
+} // levels are compiled in, so load picks one of them by name
+
+fn save(player: &mut PlayerInfo, _: &mut Renderer, _: &[&str]) -> Result<String, String> {
+    grid::save(player);
+    Ok("saved to src/level.rs".to_string())
+}
+
+impl Renderer {
+    pub fn draw_console(
+        &mut self,
+        console: &Console,
+        font: &sdl2::ttf::Font,
+    ) -> Result<(), String> {
+        if !console.open {
+            return Ok(());
+        }
+        self.canvas.set_blend_mode(BlendMode::Blend);
+        self.canvas.set_draw_color(Color::RGBA(0, 0, 0, 200));
+        self.canvas
+            .fill_rect(Rect::new(0, 0, SCREEN_WIDTH as u32, CONSOLE_HEIGHT))?;
+        self.canvas.set_blend_mode(BlendMode::None);
+        self.canvas.set_draw_color(colors::WHITE);
+        self.canvas.draw_line(
+            (0, CONSOLE_HEIGHT as i32),
+            (SCREEN_WIDTH as i32, CONSOLE_HEIGHT as i32),
+        )?;
+
+        let margin = CONSOLE_LINE_HEIGHT as i32 / 2;
+        let mut y = CONSOLE_HEIGHT as i32 - CONSOLE_LINE_HEIGHT as i32 - margin;
+        let prompt = format!("> {}_", console.input);
+        let width = ui::text_width(font, &prompt, CONSOLE_LINE_HEIGHT)?;
+        ui::draw_text(
+            self,
+            font,
+            &prompt,
+            colors::WHITE,
+            Rect::new(margin, y, width, CONSOLE_LINE_HEIGHT),
+        )?;
+        for line in console.lines.iter().rev() {
+            y -= CONSOLE_LINE_HEIGHT as i32;
+            if y < 0 {
+                break;
+            } // older lines scroll off the top
+            let width = ui::text_width(font, line, CONSOLE_LINE_HEIGHT)?;
+            ui::draw_text(
+                self,
+                font,
+                line,
+                colors::LIGHT_GREEN,
+                Rect::new(margin, y, width, CONSOLE_LINE_HEIGHT),
+            )?;
+        }
+        Ok(())
+    } // draws the console over the top half of the screen, newest lines at the bottom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_split_into_a_name_and_arguments() {
+        assert_eq!(
+            parse("teleport 1 2  3"),
+            Some(("teleport", vec!["1", "2", "3"]))
+        );
+        assert_eq!(parse("  noclip "), Some(("noclip", vec![])));
+        assert_eq!(parse("   "), None);
+    }
+
+    #[test]
+    fn config_round_trips() {
+        let path = std::env::temp_dir().join(format!("timaeus-{}.cfg", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut saved = Console::new();
+        saved.cvars[0].value = "75".to_string();
+        saved.cvars[1].value = "1".to_string();
+        saved.save_config(path).unwrap();
+
+        let mut loaded = Console::new();
+        loaded.load_config(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded.cvars, saved.cvars);
+    }
+
+    #[test]
+    fn config_ignores_unknown_and_empty_lines() {
+        let path = std::env::temp_dir().join(format!("timaeus-{}-junk.cfg", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, "r_wireframe 1\n\nnot_a_cvar 5\nhud_fps\n").unwrap();
+        let mut console = Console::new();
+        console.load_config(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(console.cvar("r_wireframe"), Some("1"));
+        assert_eq!(console.cvar("hud_fps"), Some("0")); // no value keeps the default
+        assert_eq!(console.cvar("not_a_cvar"), None);
+    }
+
+    #[test]
+    fn missing_config_is_an_error() {
+        assert!(Console::new()
+            .load_config("/nonexistent/timaeus.cfg")
+            .is_err());
+    }
+}
@@ -6,17 +6,27 @@ pub struct Grid {
     pub wall_color: Color,              // the color of the wall that is curently being drawn
     pub bottom_height: i32, // the bottom height of the sector that is currently being drawn
     pub top_height: i32,    // the top height of the sector that is currently being drawn
-    pub scale: i32,         // how large the squares of the grid appear on the screen
+    pub scale: f32,         // how many pixels on the screen make up one unit of the level
     pub selected_sector: Option<usize>, // the sector that is currently being moved
     pub selected_wall: Option<usize>, // the wall that is currently being moved
     pub selected_point: Option<usize>, // the point that is currently being moved
-    pub view_shift_x: i32,  // offset of the grid from the map in the x direction
-    pub view_shift_y: i32,  // offset of the grid from the map in the y direction
+    pub view_shift_x: f32,  // offset of the grid from the map in the x direction (in level units)
+    pub view_shift_y: f32,  // offset of the grid from the map in the y direction (in level units)
     pub highlight_x: Option<i32>, // the initial  x position of the currently drawn highlight box
     pub highlight_y: Option<i32>, // the initial  y position of the currently drawn highlight box
     pub selection: Selection, // the points, walls, & vectors that are in a highlight area
     pub state: State,
-    pub new_sector: Option<Vec<(i32, i32)>>,
+    pub new_sector: Option<Vec<(f32, f32)>>, // the points of the sector that is currently being drawn
+    pub dragging: Option<(usize, f32, f32)>, // the selected point that was grabbed & its offset from the mouse while the selection is being dragged
+    pub clipboard: Option<Level>, // sectors & their walls that have been copied and are waiting to be pasted
+    pub grid_size: i32,           // the distance in the level between the minor lines of the grid
+    pub major_lines: i32, // how many minor lines there are between each major line of the grid
+    pub snapping: bool,   // whether placed & moved points snap to the grid and to nearby points
+    pub ui: ui::Ui,       // the state of the toolbar & any other widgets drawn over the grid
+    pub browser: Option<TextureBrowser>, // the texture browser; None while it is closed
+    pub selected_entity: Option<usize>, // the entity shown in the inspector
+    pub entity_drag: Option<(f32, f32)>, // the selected entity's offset from the mouse while it is being dragged
+    pub inspector_tab: InspectorTab,     // which page of the inspector is showing
 }
 
 impl Grid {
@@ -35,12 +45,12 @@ impl Grid {
             wall_color: colors::GREEN,
             bottom_height: 0,
             top_height: 40,
-            scale: 10,
+            scale: 10.0,
             selected_sector: None,
             selected_wall: None,
             selected_point: None,
-            view_shift_x: 0,
-            view_shift_y: 0,
+            view_shift_x: 0.0,
+            view_shift_y: 0.0,
             highlight_x: None,
             highlight_y: None,
             selection: Selection {
@@ -50,9 +60,49 @@ impl Grid {
             },
             state: State::Free,
             new_sector: None,
+            dragging: None,
+            clipboard: None,
+            grid_size: 8,
+            major_lines: 8,
+            snapping: true,
+            ui: ui::Ui::new(),
+            browser: None,
+            selected_entity: None,
+            entity_drag: None,
+            inspector_tab: InspectorTab::Properties,
         }
     }
 
+    pub fn screen_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            (x + self.view_shift_x) * self.scale,
+            (y + self.view_shift_y) * self.scale,
+        )
+    } // converts a point in the level to its position on the screen
+
+    pub fn world_point(&self, x: i32, y: i32) -> (f32, f32) {
+        (
+            (x as f32 / self.scale) - self.view_shift_x,
+            (y as f32 / self.scale) - self.view_shift_y,
+        )
+    } // converts a position on the screen to a point in the level
+
+    pub fn place_camera(&self, player: &mut PlayerInfo, x: i32, y: i32) {
+        let (x, y) = self.world_point(x, y);
+        player.position.x = x as i32;
+        player.position.y = y as i32;
+    } // moves the player to a point on the screen, which is where the 3D preview is drawn from
+
+    pub fn aim_camera(&self, player: &mut PlayerInfo, x: i32, y: i32) {
+        let (x, y) = self.world_point(x, y);
+        let dx = x - player.position.x as f32;
+        let dy = y - player.position.y as f32;
+        if dx.abs() < 1.0 && dy.abs() < 1.0 {
+            return;
+        } // too close to the player to tell which way the cursor is
+        player.angle_h = dx.atan2(dy).to_degrees() as i32;
+    } // turns the player to face a point on the screen
+
     pub fn get_mouse_status(&mut self, mouse_state: MouseState) {
         self.mouse_status = MouseStatus::get(mouse_state, self.mouse_status.click_count)
     } // gets the mouse.state from the SDL event pump
@@ -63,18 +113,94 @@ impl Grid {
         self.selected_point = None;
     } // deselects all points, walls, & sectors; called every frame that the left mouse button is not pressed
 
+    pub fn grid_smaller(&mut self) {
+        self.grid_size = no_less_than_one(self.grid_size / 2)
+    }
+    pub fn grid_larger(&mut self) {
+        self.grid_size = std::cmp::min(self.grid_size * 2, 256)
+    }
+
+    pub fn snap(&self, player: &PlayerInfo, x: f32, y: f32, ignore: &[usize]) -> (f32, f32) {
+        if !self.snapping {
+            return (x, y);
+        }
+        let mut closest = 8.0 / self.scale;
+        let mut snapped = None;
+        for (w, wall) in player.level.walls.iter().enumerate() {
+            for (point, point_x, point_y) in
+                [(2 * w, wall.x1, wall.y1), (2 * w + 1, wall.x2, wall.y2)]
+            {
+                if ignore.contains(&point) {
+                    continue;
+                }
+                let point_distance = distance(x, y, point_x, point_y);
+                if point_distance <= closest {
+                    closest = point_distance;
+                    snapped = Some((point_x, point_y));
+                }
+            }
+        }
+        match snapped {
+            Some(point) => point,
+            _ => {
+                let size = self.grid_size as f32;
+                ((x / size).round() * size, (y / size).round() * size)
+            }
+        }
+    } // snaps a point in the level to any point within 8 pixels of it, or otherwise to the nearest grid intersection
+
     pub fn view_down(&mut self) {
-        self.view_shift_y -= 10
+        self.view_shift_y -= 50.0 / self.scale
     }
     pub fn view_up(&mut self) {
-        self.view_shift_y += 10
+        self.view_shift_y += 50.0 / self.scale
     }
     pub fn view_left(&mut self) {
-        self.view_shift_x += 10
+        self.view_shift_x += 50.0 / self.scale
     }
     pub fn view_right(&mut self) {
-        self.view_shift_x -= 10
-    }
+        self.view_shift_x -= 50.0 / self.scale
+    } // each key press moves the view by the same distance on the screen no matter the scale
+
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        self.view_shift_x += dx as f32 / self.scale;
+        self.view_shift_y += dy as f32 / self.scale;
+    } // moves the view along with the mouse
+
+    pub fn zoom(&mut self, factor: f32, x: i32, y: i32) {
+        let (world_x, world_y) = self.world_point(x, y);
+        self.scale = (self.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+        self.view_shift_x = x as f32 / self.scale - world_x;
+        self.view_shift_y = y as f32 / self.scale - world_y;
+    } // zooms in or out while keeping the point of the level underneath the given position in place
+
+    pub fn frame_level(&mut self, player: &PlayerInfo, area: Rect) {
+        if player.level.walls.is_empty() {
+            return;
+        }
+        let mut min_x = f32::MAX;
+        let mut min_y = f32::MAX;
+        let mut max_x = f32::MIN;
+        let mut max_y = f32::MIN;
+        for wall in player.level.walls.iter() {
+            min_x = min_x.min(wall.x1).min(wall.x2);
+            min_y = min_y.min(wall.y1).min(wall.y2);
+            max_x = max_x.max(wall.x1).max(wall.x2);
+            max_y = max_y.max(wall.y1).max(wall.y2);
+        }
+        let view_width = area.width() as f32;
+        let view_height = (6 * area.height() / 7) as f32; // the area above the toolbar
+        self.scale = (0.9
+            * f32::min(
+                view_width / one_if_none(max_x - min_x),
+                view_height / one_if_none(max_y - min_y),
+            ))
+        .clamp(MIN_SCALE, MAX_SCALE);
+        self.view_shift_x =
+            (area.x() as f32 + view_width / 2.0) / self.scale - (min_x + max_x) / 2.0;
+        self.view_shift_y =
+            (area.y() as f32 + view_height / 2.0) / self.scale - (min_y + max_y) / 2.0;
+    } // zooms & moves the view so that the whole level fits in the part of the screen the editor has
 
     pub fn next_wall(&mut self, player: &mut PlayerInfo) {
         match self.selected_wall {
@@ -118,6 +244,16 @@ impl Grid {
                 texture: Some(textures::BRAT_TEXTURE),
                 u: 1.0,
                 v: 1.0,
+                tag: 0,
+                trigger: None,
+                masked: false,
+                opacity: 255,
+                blocking: true,
+                upper: WallTexture::NONE,
+                lower: WallTexture::NONE,
+                offset_x: 0.0,
+                offset_y: 0.0,
+                world_aligned: false,
             },
             Wall {
                 x1: 64.0,
@@ -128,6 +264,16 @@ impl Grid {
                 texture: Some(textures::BRAT_TEXTURE),
                 u: 1.0,
                 v: 1.0,
+                tag: 0,
+                trigger: None,
+                masked: false,
+                opacity: 255,
+                blocking: true,
+                upper: WallTexture::NONE,
+                lower: WallTexture::NONE,
+                offset_x: 0.0,
+                offset_y: 0.0,
+                world_aligned: false,
             },
             Wall {
                 x1: 64.0,
@@ -138,6 +284,16 @@ impl Grid {
                 texture: Some(textures::BRAT_TEXTURE),
                 u: 1.0,
                 v: 1.0,
+                tag: 0,
+                trigger: None,
+                masked: false,
+                opacity: 255,
+                blocking: true,
+                upper: WallTexture::NONE,
+                lower: WallTexture::NONE,
+                offset_x: 0.0,
+                offset_y: 0.0,
+                world_aligned: false,
             },
             Wall {
                 x1: 32.0,
@@ -148,6 +304,16 @@ impl Grid {
                 texture: Some(textures::BRAT_TEXTURE),
                 u: 1.0,
                 v: 1.0,
+                tag: 0,
+                trigger: None,
+                masked: false,
+                opacity: 255,
+                blocking: true,
+                upper: WallTexture::NONE,
+                lower: WallTexture::NONE,
+                offset_x: 0.0,
+                offset_y: 0.0,
+                world_aligned: false,
             },
         ];
         player.level.walls.append(&mut new_sector_walls.to_vec());
@@ -163,16 +329,308 @@ impl Grid {
             surface_points: [0; SCREEN_WIDTH],
             surface: None,
             surface_texture: None,
+            light: 255,
+            mover: None,
+            tag: 0,
+            trigger: None,
+            sky: false,
         });
         player.level.number_of_sectors += 1;
+        player.level.link_walls();
     } // creates a new cyan sector in the center of the grid
+
+    pub fn select_rectangle(&mut self, player: &PlayerInfo, highlight: Rect) {
+        self.selection.clear();
+        for s in 0..player.level.number_of_sectors as usize {
+            let sector = player.level.sectors[s];
+            let mut whole_sector = true;
+            for w in sector.wall_start as usize..sector.wall_end as usize {
+                let wall = player.level.walls[w];
+                let (x1, y1) = self.screen_point(wall.x1, wall.y1);
+                let (x2, y2) = self.screen_point(wall.x2, wall.y2);
+                let first = highlight.contains_point((x1 as i32, y1 as i32));
+                let second = highlight.contains_point((x2 as i32, y2 as i32));
+                if first {
+                    self.selection.points.push(2 * w);
+                }
+                if second {
+                    self.selection.points.push(2 * w + 1);
+                }
+                if first && second {
+                    self.selection.walls.push(w);
+                } else {
+                    whole_sector = false;
+                }
+            }
+            if whole_sector {
+                self.selection.sectors.push(s);
+            }
+        }
+    } // fills the selection with every point, wall, & sector that is inside of the highlighted area
+
+    pub fn selected_endpoints(&self, player: &PlayerInfo) -> Vec<usize> {
+        let mut endpoints = self.selection.points.clone();
+        for wall in self.selection.walls.iter() {
+            endpoints.push(2 * wall);
+            endpoints.push(2 * wall + 1);
+        }
+        for sector in self.selection.sectors.iter() {
+            let sector = player.level.sectors[*sector];
+            for wall in sector.wall_start as usize..sector.wall_end as usize {
+                endpoints.push(2 * wall);
+                endpoints.push(2 * wall + 1);
+            }
+        }
+        endpoints.sort();
+        endpoints.dedup();
+        endpoints
+    } // collects the points of every selected point, wall, & sector so that each is only moved once
+
+    pub fn selected_point_at(&self, player: &PlayerInfo, x: i32, y: i32) -> Option<usize> {
+        for point in self.selected_endpoints(player) {
+            let (point_x, point_y) = endpoint(player, point);
+            let (point_x, point_y) = self.screen_point(point_x, point_y);
+            if distance(x as f32, y as f32, point_x, point_y) <= 8.0 {
+                return Some(point);
+            }
+        }
+        None
+    } // finds the selected point (if any) underneath a position on the screen
+
+    pub fn drag_selection(&mut self, player: &mut PlayerInfo, mouse_x: f32, mouse_y: f32) {
+        match self.dragging {
+            Some((point, offset_x, offset_y)) => {
+                let ignore = self.selected_endpoints(player);
+                let (x, y) = self.snap(player, mouse_x + offset_x, mouse_y + offset_y, &ignore);
+                let (point_x, point_y) = endpoint(player, point);
+                self.move_selection(player, x - point_x, y - point_y);
+            }
+            _ => {}
+        }
+    } // moves the whole selection so that the grabbed point follows the mouse
+
+    pub fn entity_at(&self, player: &PlayerInfo, x: i32, y: i32) -> Option<usize> {
+        for (e, entity) in player.level.entities.iter().enumerate().rev() {
+            let (entity_x, entity_y) = self.screen_point(entity.x, entity.y);
+            if distance(x as f32, y as f32, entity_x, entity_y) <= 8.0 {
+                return Some(e);
+            }
+        }
+        None
+    } // finds the entity (if any) whose icon is underneath a position on the screen
+
+    pub fn add_entity(&mut self, player: &mut PlayerInfo, x: i32, y: i32) {
+        let (x, y) = self.world_point(x, y);
+        let (x, y) = self.snap(player, x, y, &[]);
+        let mut entity = Entity::new(EntityKind::Decoration, x, y);
+        entity.z = self.bottom_height as f32;
+        player.level.entities.push(entity);
+        player.level.spawn();
+        self.selected_entity = Some(player.level.entities.len() - 1);
+    } // places a new entity under the mouse and selects it
+
+    pub fn delete_entity(&mut self, player: &mut PlayerInfo) {
+        match self.selected_entity {
+            Some(e) if e < player.level.entities.len() => {
+                player.level.entities.remove(e);
+                player.level.spawn();
+            }
+            _ => {}
+        }
+        self.selected_entity = None;
+        self.entity_drag = None;
+    } // removes the selected entity from the level
+
+    pub fn drag_entity(&mut self, player: &mut PlayerInfo, mouse_x: f32, mouse_y: f32) {
+        match (self.selected_entity, self.entity_drag) {
+            (Some(e), Some((offset_x, offset_y))) => {
+                let (x, y) = self.snap(player, mouse_x + offset_x, mouse_y + offset_y, &[]);
+                let entity = &mut player.level.entities[e];
+                if (entity.x, entity.y) != (x, y) {
+                    (entity.x, entity.y) = (x, y);
+                    player.level.spawn();
+                } // the preview follows the entity while it is dragged
+            }
+            _ => {}
+        }
+    } // moves the grabbed entity so that it follows the mouse
+
+    pub fn toggle_browser(&mut self) {
+        match self.browser {
+            Some(_) => self.browser = None,
+            _ => {
+                self.browser = Some(TextureBrowser {
+                    filter: String::new(),
+                    scroll: 0,
+                    target: BrowserTarget::Walls,
+                })
+            }
+        }
+    } // opens or closes the texture browser
+
+    pub fn selected_walls(&self, player: &PlayerInfo) -> Vec<usize> {
+        let mut walls = self.selection.walls.clone();
+        for sector in self.selection.sectors.iter() {
+            let sector = player.level.sectors[*sector];
+            walls.extend(sector.wall_start as usize..sector.wall_end as usize);
+        }
+        if walls.is_empty() {
+            match self.selected_wall {
+                Some(wall) => walls.push(wall),
+                _ => {}
+            }
+        }
+        walls.sort();
+        walls.dedup();
+        walls
+    } // the walls in the selection, or the selected wall if nothing is highlighted
+
+    pub fn selected_sectors(&self) -> Vec<usize> {
+        match (self.selection.sectors.is_empty(), self.selected_sector) {
+            (true, Some(sector)) => vec![sector],
+            _ => self.selection.sectors.clone(),
+        }
+    } // the sectors in the selection, or the selected sector if nothing is highlighted
+
+    pub fn apply_texture(&self, player: &mut PlayerInfo, target: BrowserTarget, texture: Texture) {
+        match target {
+            BrowserTarget::Walls => {
+                for wall in self.selected_walls(player) {
+                    player.level.walls[wall].texture = Some(texture);
+                }
+            }
+            BrowserTarget::Surface => {
+                for sector in self.selected_sectors() {
+                    player.level.sectors[sector].surface_texture = Some(texture);
+                }
+            }
+        }
+    } // gives the selected walls or sector surfaces a texture
+
+    pub fn move_vertex(player: &mut PlayerInfo, from: (f32, f32), to: (f32, f32)) {
+        for wall in player.level.walls.iter_mut() {
+            if (wall.x1, wall.y1) == from {
+                (wall.x1, wall.y1) = to;
+            }
+            if (wall.x2, wall.y2) == from {
+                (wall.x2, wall.y2) = to;
+            }
+        }
+        player.level.link_walls();
+    } // moves every wall point that is at the same position
+
+    pub fn nudge_selection(&mut self, player: &mut PlayerInfo, dx: f32, dy: f32) {
+        let step = match self.snapping {
+            true => self.grid_size as f32,
+            false => 1.0,
+        };
+        self.move_selection(player, dx * step, dy * step);
+    } // moves the selection by one grid square (or by one unit if snapping is turned off)
+
+    pub fn move_selection(&mut self, player: &mut PlayerInfo, dx: f32, dy: f32) {
+        for point in self.selected_endpoints(player) {
+            let wall = &mut player.level.walls[point / 2];
+            match point % 2 {
+                0 => {
+                    wall.x1 += dx;
+                    wall.y1 += dy;
+                }
+                _ => {
+                    wall.x2 += dx;
+                    wall.y2 += dy;
+                }
+            }
+        }
+        if (dx, dy) != (0.0, 0.0) {
+            player.level.link_walls();
+        } // dragging calls this every frame, even when the mouse is still
+    } // moves everything in the selection together
+
+    pub fn copy_selection(&mut self, player: &PlayerInfo) {
+        let mut sectors = self.selection.sectors.clone();
+        if sectors.is_empty() {
+            match self.selected_sector {
+                Some(sector) => sectors.push(sector),
+                _ => return,
+            }
+        }
+        let mut clipboard = Level::default();
+        for s in sectors {
+            let mut sector = player.level.sectors[s];
+            let walls = &player.level.walls[sector.wall_start as usize..sector.wall_end as usize];
+            sector.wall_start = clipboard.number_of_walls as i32;
+            sector.wall_end = sector.wall_start + walls.len() as i32;
+            clipboard.walls.extend_from_slice(walls);
+            clipboard.number_of_walls += walls.len() as u32;
+            clipboard.sectors.push(sector);
+            clipboard.number_of_sectors += 1;
+        }
+        self.clipboard = Some(clipboard);
+    } // copies the selected sectors (or the sector of the selected wall) & their walls to the clipboard
+
+    pub fn paste(&mut self, player: &mut PlayerInfo, x: f32, y: f32) {
+        let clipboard = match &self.clipboard {
+            Some(clipboard) => clipboard,
+            _ => return,
+        };
+        let mut min_x = f32::MAX;
+        let mut min_y = f32::MAX;
+        for wall in clipboard.walls.iter() {
+            min_x = min_x.min(wall.x1).min(wall.x2);
+            min_y = min_y.min(wall.y1).min(wall.y2);
+        }
+        let dx = x - min_x;
+        let dy = y - min_y;
+
+        let first_wall = player.level.number_of_walls as i32;
+        let mut pasted = Vec::new();
+        for mut wall in clipboard.walls.clone() {
+            wall.x1 += dx;
+            wall.y1 += dy;
+            wall.x2 += dx;
+            wall.y2 += dy;
+            player.level.walls.push(wall);
+            player.level.number_of_walls += 1;
+        }
+        for mut sector in clipboard.sectors.clone() {
+            sector.wall_start += first_wall;
+            sector.wall_end += first_wall;
+            sector.distance = 0.0;
+            pasted.push(player.level.sectors.len());
+            player.level.sectors.push(sector);
+            player.level.number_of_sectors += 1;
+        }
+        player.level.link_walls();
+        self.selection.clear();
+        self.selection.sectors = pasted;
+    } // adds a copy of the clipboard to the level with its top left corner at the given point & selects it
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Selection {
     pub sectors: Vec<usize>,
     pub walls: Vec<usize>,
-    pub points: Vec<usize>,
+    pub points: Vec<usize>, // 2 * wall for the first point of a wall and 2 * wall + 1 for its second point
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureBrowser {
+    pub filter: String,        // only textures with this in their name are shown
+    pub scroll: i32,           // how many rows of thumbnails have been scrolled past
+    pub target: BrowserTarget, // what a clicked texture is given to
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InspectorTab {
+    Properties, // how the selected sector & wall look and move
+    Triggers,   // their tags & what they set off
+    Textures,   // the wall's upper, middle & lower textures
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrowserTarget {
+    Walls,
+    Surface,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -192,8 +650,26 @@ impl Selection {
             points,
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.sectors.is_empty() && self.walls.is_empty() && self.points.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.sectors = Vec::new();
+        self.walls = Vec::new();
+        self.points = Vec::new();
+    }
 }
 
+pub fn endpoint(player: &PlayerInfo, point: usize) -> (f32, f32) {
+    let wall = player.level.walls[point / 2];
+    match point % 2 {
+        0 => (wall.x1, wall.y1),
+        _ => (wall.x2, wall.y2),
+    }
+} // returns the position of a point numbered the same way as selection.points
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Button {
     Left,
@@ -245,7 +721,7 @@ pub fn save(player: &mut PlayerInfo) -> () {
         .expect("Failed to read level.rs loser!");
 
     let header = format!(
-        "pub use sdl2::pixels::Color;\npub use crate::{{colors, textures, Sector, Surface, Wall}};\npub const NUM_SECTORS: usize = {:?}; \npub const NUM_WALLS: usize = {:?};\n\n//SECTORS:\npub const INIT_SECTORS: [Sector; NUM_SECTORS] = [",
+        "pub use sdl2::pixels::Color;\npub use crate::{{colors, textures, Action, Activation, Entity, EntityKind, Mover, MoverKind, Sector, Surface, Trigger, TriggerEvent, Wall, WallTexture}};\npub const NUM_SECTORS: usize = {:?}; \npub const NUM_WALLS: usize = {:?};\n\n//SECTORS:\npub const INIT_SECTORS: [Sector; NUM_SECTORS] = [",
         player.level.number_of_sectors, player.level.number_of_walls
     );
     file.write_all(header.as_bytes())
@@ -253,7 +729,7 @@ pub fn save(player: &mut PlayerInfo) -> () {
 
     for s in 0..player.level.number_of_sectors {
         let sector = format!(
-            "Sector{{\n wall_start:{:?},\n wall_end:{:?},\n bottom_height:{:?},\n top_height:{:?},\n distance:{:?},\n top_color:Color::RGBA{:?},\n bottom_color:Color::RGBA{:?},\n surface:{:?},\n surface_points:[0; crate::SCREEN_WIDTH],\n surface_texture:{}\n}},\n\n",
+            "Sector{{\n wall_start:{:?},\n wall_end:{:?},\n bottom_height:{:?},\n top_height:{:?},\n distance:{:?},\n top_color:Color::RGBA{:?},\n bottom_color:Color::RGBA{:?},\n surface:{:?},\n surface_points:[0; crate::SCREEN_WIDTH],\n surface_texture:{},\n light:{:?},\n mover:{},\n tag:{:?},\n trigger:{},\n sky:{:?}\n}},\n\n",
             player.level.sectors[s as usize].wall_start,
             player.level.sectors[s as usize].wall_end,
             player.level.sectors[s as usize].bottom_height,
@@ -267,7 +743,17 @@ pub fn save(player: &mut PlayerInfo) -> () {
                 Some(texture) => format!("Some(textures::{})", texture.name),
                 _ => "None".to_string(),
             },
-
+            player.level.sectors[s as usize].light,
+            match player.level.sectors[s as usize].mover {
+                Some(mover) => format!(
+                    "Some(Mover{{ kind:MoverKind::{:?}, activation:Activation::{:?}, bottom:{:?}, top:{:?}, speed:{:?}, wait:{:?} }})",
+                    mover.kind, mover.activation, mover.bottom, mover.top, mover.speed, mover.wait
+                ),
+                _ => "None".to_string(),
+            },
+            player.level.sectors[s as usize].tag,
+            trigger_source(player.level.sectors[s as usize].trigger),
+            player.level.sectors[s as usize].sky,
         );
         file.write_all(sector.as_bytes())
             .expect("Unable to write your data loser!");
@@ -287,26 +773,77 @@ pub fn save(player: &mut PlayerInfo) -> () {
         let x2 = player.level.walls[w as usize].x2;
         let y2 = player.level.walls[w as usize].y2;
         let wall = format!(
-            "Wall{{\n x1:{:?},\n y1:{:?},\n x2:{:?},\n y2:{:?},\n color:Color::RGBA{:?},\n texture:Some(textures::{}),\n u:{:?},\n v:{:?}}},\n\n",
+            "Wall{{\n x1:{:?},\n y1:{:?},\n x2:{:?},\n y2:{:?},\n color:Color::RGBA{:?},\n texture:{},\n u:{:?},\n v:{:?},\n tag:{:?},\n trigger:{},\n masked:{:?},\n opacity:{:?},\n blocking:{:?},\n upper:{},\n lower:{},\n offset_x:{:?},\n offset_y:{:?},\n world_aligned:{:?}}},\n\n",
             x1,
             y1,
             x2,
             y2,
             player.level.walls[w as usize].color.rgba(),
-            player.level.walls[w as usize].texture.unwrap().name,
+            match player.level.walls[w as usize].texture {
+                Some(texture) => format!("Some(textures::{})", texture.name),
+                _ => "None".to_string(),
+            },
             player.level.walls[w as usize].u,
             player.level.walls[w as usize].v,
+            player.level.walls[w as usize].tag,
+            trigger_source(player.level.walls[w as usize].trigger),
+            player.level.walls[w as usize].masked,
+            player.level.walls[w as usize].opacity,
+            player.level.walls[w as usize].blocking,
+            wall_texture_source(player.level.walls[w as usize].upper),
+            wall_texture_source(player.level.walls[w as usize].lower),
+            player.level.walls[w as usize].offset_x,
+            player.level.walls[w as usize].offset_y,
+            player.level.walls[w as usize].world_aligned,
         );
         file.write_all(wall.as_bytes())
             .expect("Unable to write your data loser!")
     }
-    file.write_all("\n];\n".as_bytes())
+    file.write_all(
+        "\n];\n\n//ENTITIES:\npub fn init_entities() -> Vec<Entity> {\n vec![".as_bytes(),
+    )
+    .expect("Unable to write your data loser!");
+
+    for entity in player.level.entities.iter() {
+        let mut properties = String::new();
+        for (key, value) in entity.properties.iter() {
+            properties += &format!("({:?}.to_string(), {:?}.to_string()), ", key, value);
+        }
+        let entity = format!(
+            "Entity{{\n kind:EntityKind::{:?},\n x:{:?},\n y:{:?},\n z:{:?},\n angle:{:?},\n properties:vec![{}]}},\n\n",
+            entity.kind, entity.x, entity.y, entity.z, entity.angle, properties,
+        );
+        file.write_all(entity.as_bytes())
+            .expect("Unable to write your data loser!")
+    }
+    file.write_all("]\n}\n".as_bytes())
         .expect("Unable to write your data loser!");
 
     println!("Level Saved ~<3")
 } // writes the current level to level.rs, deleting whatever data is currently there
   // !!!CAUTION!!! NOT REVERSIBLE !!!
 
+fn trigger_source(trigger: Option<Trigger>) -> String {
+    let trigger = match trigger {
+        Some(trigger) => trigger,
+        _ => return "None".to_string(),
+    };
+    let action = match trigger.action {
+        Action::ChangeTexture { texture } => format!(
+            "Action::ChangeTexture{{ texture:{} }}",
+            match texture {
+                Some(texture) => format!("Some(textures::{})", texture.name),
+                _ => "None".to_string(),
+            }
+        ), // textures are written by name rather than by their data
+        action => format!("Action::{:?}", action),
+    };
+    format!(
+        "Some(Trigger{{ event:TriggerEvent::{:?}, action:{}, target:{:?}, once:{:?} }})",
+        trigger.event, action, trigger.target, trigger.once
+    )
+} // writes a trigger the way it would be written in level.rs
+
 impl renderer::Renderer {
     pub fn draw_big_dot(&mut self, x: f32, y: f32, color: Color) -> Result<(), String> {
         self.canvas.set_draw_color(color);
@@ -340,6 +877,40 @@ impl renderer::Renderer {
         Ok(())
     } // draws a line using big_dot
 
+    pub fn draw_grid(&mut self, grid: &Grid) -> Result<(), String> {
+        let size = grid.grid_size as f32;
+        let minor = size * grid.scale >= 4.0; // minor lines are skipped once they get too close together
+        let area = renderer::editor_area(self.draw_mode);
+        let (left, top) = grid.world_point(area.left(), area.top());
+        let (right, bottom) = grid.world_point(area.right(), area.bottom());
+
+        let mut line = (left / size).floor() as i32;
+        while line as f32 * size <= right {
+            if line % grid.major_lines == 0 || minor {
+                match line % grid.major_lines {
+                    0 => self.canvas.set_draw_color(colors::BLACK),
+                    _ => self.canvas.set_draw_color(colors::GREY3),
+                }
+                let x = grid.screen_point(line as f32 * size, 0.0).0 as i32;
+                self.canvas.draw_line((x, area.top()), (x, area.bottom()))?;
+            }
+            line += 1;
+        } // vertical lines
+        let mut line = (top / size).floor() as i32;
+        while line as f32 * size <= bottom {
+            if line % grid.major_lines == 0 || minor {
+                match line % grid.major_lines {
+                    0 => self.canvas.set_draw_color(colors::BLACK),
+                    _ => self.canvas.set_draw_color(colors::GREY3),
+                }
+                let y = grid.screen_point(0.0, line as f32 * size).1 as i32;
+                self.canvas.draw_line((area.left(), y), (area.right(), y))?;
+            }
+            line += 1;
+        } // horizontal lines
+        Ok(())
+    } // draws the minor & major lines of the grid lined up with the level
+
     pub fn draw_circle(&mut self, x: f32, y: f32, radius: u32, color: Color) -> Result<(), String> {
         for t in 0..360 {
             let x1 = x + (radius as f32 * cosine(t));
@@ -361,8 +932,8 @@ impl renderer::Renderer {
         self.canvas.fill_rect(Rect::new(
             x * PIXEL_SCALE as i32,
             y * PIXEL_SCALE as i32,
-            grid.scale as u32 * 2 * PIXEL_SCALE as u32,
-            grid.scale as u32 * 2 * PIXEL_SCALE as u32,
+            (grid.scale * 2.0) as u32 * PIXEL_SCALE as u32,
+            (grid.scale * 2.0) as u32 * PIXEL_SCALE as u32,
         ))?;
 
         for t in player.angle_h as i32 - 22..player.angle_h as i32 + 22 {
@@ -379,10 +950,7 @@ impl renderer::Renderer {
         font: &sdl2::ttf::Font,
         string: String,
         color: Color,
-        x: i32,
-        y: i32,
-        width: u32,
-        height: u32,
+        text_box: Rect,
     ) -> Result<(), String> {
         let surface = font
             .render(&string)
@@ -391,7 +959,6 @@ impl renderer::Renderer {
         let texture = texture_creator
             .create_texture_from_surface(&surface)
             .map_err(|e| e.to_string())?;
-        let text_box = Rect::new(x, y, width, height);
         render.canvas.copy(&texture, None, text_box)?;
         Ok(())
     } // creates all the neccessary components to render text
@@ -418,101 +985,87 @@ impl renderer::Renderer {
             8 => colors::ORANGE,
             _ => colors::BLACK,
         };
+        let (mouse_x, mouse_y) =
+            grid.world_point(grid.mouse_status.mouse_x, grid.mouse_status.mouse_y);
+        let cursor = grid.snap(player, mouse_x, mouse_y, &[]); // the point that will be placed on the next click
+        let (cursor_x, cursor_y) = grid.screen_point(cursor.0, cursor.1);
+        let screen_points: Vec<(f32, f32)> = match &grid.new_sector {
+            Some(points) => points
+                .iter()
+                .map(|(x, y)| grid.screen_point(*x, *y))
+                .collect(),
+            _ => Vec::new(),
+        };
         match &mut grid.new_sector {
             Some(points) => match grid.mouse_status.click_count {
                 0 => {
-                    self.draw_dot(
-                        grid.mouse_status.mouse_x as f32,
-                        grid.mouse_status.mouse_y as f32,
-                        colors::WHITE,
-                    )?;
+                    self.draw_dot(cursor_x, cursor_y, colors::WHITE)?;
                     if grid.mouse_status.button == Some(Button::Left) {
-                        points.push((
-                            grid.mouse_status.mouse_x as i32,
-                            grid.mouse_status.mouse_y as i32,
-                        ));
+                        points.push(cursor);
                         grid.mouse_status.click_count += 1;
                     }
                 }
                 1 => {
                     self.draw_thick_line(
-                        points[0].0 as f32,
-                        points[0].1 as f32,
-                        grid.mouse_status.mouse_x as f32,
-                        grid.mouse_status.mouse_y as f32,
+                        screen_points[0].0,
+                        screen_points[0].1,
+                        cursor_x,
+                        cursor_y,
                         colors::WHITE,
                     )?;
                     if grid.mouse_status.button == Some(Button::Left) {
-                        points.push((
-                            grid.mouse_status.mouse_x as i32,
-                            grid.mouse_status.mouse_y as i32,
-                        ));
+                        points.push(cursor);
                         grid.mouse_status.click_count += 1;
                     }
                 }
                 2 => {
                     self.draw_thick_line(
-                        points[0].0 as f32,
-                        points[0].1 as f32,
-                        points[1].0 as f32,
-                        points[1].1 as f32,
+                        screen_points[0].0,
+                        screen_points[0].1,
+                        screen_points[1].0,
+                        screen_points[1].1,
                         colors::GREY3,
                     )?;
                     self.draw_thick_line(
-                        points[1].0 as f32,
-                        points[1].1 as f32,
-                        grid.mouse_status.mouse_x as f32,
-                        grid.mouse_status.mouse_y as f32,
+                        screen_points[1].0,
+                        screen_points[1].1,
+                        cursor_x,
+                        cursor_y,
                         colors::WHITE,
                     )?;
                     if grid.mouse_status.button == Some(Button::Left) {
-                        points.push((
-                            grid.mouse_status.mouse_x as i32,
-                            grid.mouse_status.mouse_y as i32,
-                        ));
+                        points.push(cursor);
                         grid.mouse_status.click_count += 1;
                     }
                 }
                 _ => {
-                    for (i, (x, y)) in points.iter().enumerate() {
-                        if i == points.len() - 1 {
-                            self.draw_thick_line(
-                                *x as f32,
-                                *y as f32,
-                                grid.mouse_status.mouse_x as f32,
-                                grid.mouse_status.mouse_y as f32,
-                                colors::WHITE,
-                            )?;
+                    for (i, (x, y)) in screen_points.iter().enumerate() {
+                        if i == screen_points.len() - 1 {
+                            self.draw_thick_line(*x, *y, cursor_x, cursor_y, colors::WHITE)?;
                         } else {
                             self.draw_thick_line(
-                                *x as f32,
-                                *y as f32,
-                                points[i + 1].0 as f32,
-                                points[i + 1].1 as f32,
+                                *x,
+                                *y,
+                                screen_points[i + 1].0,
+                                screen_points[i + 1].1,
                                 colors::GREY3,
                             )?;
                         }
                     }
                     if grid.mouse_status.button == Some(Button::Left) {
-                        points.push((
-                            grid.mouse_status.mouse_x as i32,
-                            grid.mouse_status.mouse_y as i32,
-                        ));
+                        points.push(cursor);
                         grid.mouse_status.click_count += 1;
                     }
                     let distance = distance(
-                        points.first().unwrap().0 as f32,
-                        points.first().unwrap().1 as f32,
-                        points.last().unwrap().0 as f32,
-                        points.last().unwrap().1 as f32,
-                    );
+                        points.first().unwrap().0,
+                        points.first().unwrap().1,
+                        points.last().unwrap().0,
+                        points.last().unwrap().1,
+                    ) * grid.scale;
 
                     if points.len() >= 3 && distance <= 8.0 {
                         if grid.mouse_status.button == Some(Button::Left) {
-                            points.push((
-                                grid.mouse_status.mouse_x as i32,
-                                grid.mouse_status.mouse_y as i32,
-                            ));
+                            points.push(cursor);
                             grid.mouse_status.click_count += 1;
                         }
 
@@ -528,44 +1081,64 @@ impl renderer::Renderer {
                             surface: None,
                             surface_points: [0; SCREEN_WIDTH],
                             surface_texture: Some(textures::BRAT_TEXTURE),
+                            light: 255,
+                            mover: None,
+                            tag: 0,
+                            trigger: None,
+                            sky: false,
                         });
                         player.level.number_of_sectors += 1;
 
                         for (i, (x, y)) in points.iter().enumerate() {
                             if i == points.len() - 1 {
                                 player.level.walls.push(Wall {
-                                    x1: ((points[0].0 as f32) / grid.scale as f32)
-                                        - grid.view_shift_x as f32,
-                                    y1: ((points[0].1 as f32) / grid.scale as f32)
-                                        - grid.view_shift_y as f32,
-                                    x2: ((points[i - 1].0 as f32) / grid.scale as f32
-                                        - grid.view_shift_x as f32),
-                                    y2: ((points[i - 1].1 as f32) / grid.scale as f32)
-                                        - grid.view_shift_y as f32,
+                                    x1: points[0].0,
+                                    y1: points[0].1,
+                                    x2: points[i - 1].0,
+                                    y2: points[i - 1].1,
 
                                     color: new_color,
                                     u: 1.0,
                                     v: 1.0,
                                     texture: Some(textures::BRAT_TEXTURE),
+                                    tag: 0,
+                                    trigger: None,
+                                    masked: false,
+                                    opacity: 255,
+                                    blocking: true,
+                                    upper: WallTexture::NONE,
+                                    lower: WallTexture::NONE,
+                                    offset_x: 0.0,
+                                    offset_y: 0.0,
+                                    world_aligned: false,
                                 });
                                 player.level.number_of_walls += 1;
                             } else {
                                 player.level.walls.push(Wall {
-                                    x1: (*x as f32 / grid.scale as f32) - grid.view_shift_x as f32,
-                                    y1: (*y as f32 / grid.scale as f32) - grid.view_shift_y as f32,
-                                    x2: (points[i + 1].0 as f32 / grid.scale as f32)
-                                        - grid.view_shift_x as f32,
-                                    y2: (points[i + 1].1 as f32 / grid.scale as f32)
-                                        - grid.view_shift_y as f32,
+                                    x1: *x,
+                                    y1: *y,
+                                    x2: points[i + 1].0,
+                                    y2: points[i + 1].1,
 
                                     color: new_color,
                                     u: 1.0,
                                     v: 1.0,
                                     texture: Some(textures::BRAT_TEXTURE),
+                                    tag: 0,
+                                    trigger: None,
+                                    masked: false,
+                                    opacity: 255,
+                                    blocking: true,
+                                    upper: WallTexture::NONE,
+                                    lower: WallTexture::NONE,
+                                    offset_x: 0.0,
+                                    offset_y: 0.0,
+                                    world_aligned: false,
                                 });
                                 player.level.number_of_walls += 1;
                             }
                         }
+                        player.level.link_walls();
                         grid.new_sector = None;
                         grid.mouse_status.click_count = 0;
                     }
@@ -575,11 +1148,7 @@ impl renderer::Renderer {
                 grid.mouse_status.click_count = 0;
                 grid.new_sector = Some(vec![]);
 
-                self.draw_dot(
-                    grid.mouse_status.mouse_x as f32,
-                    grid.mouse_status.mouse_y as f32,
-                    colors::WHITE,
-                )?;
+                self.draw_dot(cursor_x, cursor_y, colors::WHITE)?;
             }
         }
         Ok(())
@@ -593,8 +1162,8 @@ impl renderer::Renderer {
         draw: bool,
     ) -> Result<Rect, String> {
         let selection = Rect::new(
-            init_x,
-            init_y,
+            std::cmp::min(init_x, grid.mouse_status.mouse_x),
+            std::cmp::min(init_y, grid.mouse_status.mouse_y),
             (grid.mouse_status.mouse_x - init_x).abs() as u32,
             (grid.mouse_status.mouse_y - init_y).abs() as u32,
         );
@@ -612,108 +1181,1092 @@ impl renderer::Renderer {
         Ok(selection)
     } // generates the current highlight rectangle and optionally draws it
 
-    pub fn draw2d(
-        // Master function for the the level builder;
-        &mut self, // Draws a 2D representation of the sectors & walls in level.rs
+    pub fn draw_toolbar(&mut self, grid: &mut Grid, font: &sdl2::ttf::Font) -> Result<(), String> {
+        let ui = &mut grid.ui;
+        let area = renderer::editor_area(self.draw_mode);
+        let toolbar = Rect::new(
+            area.x(),
+            area.y() + (6 * area.height() / 7) as i32,
+            area.width(),
+            area.height() / 7,
+        );
+        ui::panel(self, ui, toolbar, colors::GREY4)?;
+        let mut layout = ui::Layout::new(toolbar, 3);
+
+        layout.column(0.25);
+        match (grid.selected_sector, grid.selected_wall) {
+            (Some(s), Some(w)) => {
+                ui::label(
+                    self,
+                    &mut layout,
+                    font,
+                    &format!("Sector:{}", s),
+                    colors::BLACK,
+                )?;
+                layout.row();
+                ui::label(
+                    self,
+                    &mut layout,
+                    font,
+                    &format!("Wall:{}", w),
+                    colors::BLACK,
+                )?;
+            }
+            _ => {
+                ui::label(self, &mut layout, font, "Nothing selected", colors::BLACK)?;
+            }
+        }
+        layout.row();
+        if ui::tab(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "Textures",
+            grid.browser.is_some(),
+        )? {
+            grid.toggle_browser();
+        }
+        layout.column(0.4);
+
+        let (mouse_x, mouse_y) =
+            grid.world_point(grid.mouse_status.mouse_x, grid.mouse_status.mouse_y);
+        let grid_text = match grid.snapping {
+            true => format!("Grid: {}", grid.grid_size),
+            false => format!("Grid: {} (off)", grid.grid_size),
+        };
+        layout.column(0.3);
+        ui::label(
+            self,
+            &mut layout,
+            font,
+            &format!("Mouse x: {:?}", mouse_x),
+            colors::BLACK,
+        )?;
+        layout.row();
+        ui::label(
+            self,
+            &mut layout,
+            font,
+            &format!("Mouse y: {:?}", mouse_y),
+            colors::BLACK,
+        )?;
+        layout.row();
+        ui::label(self, &mut layout, font, &grid_text, colors::BLACK)?;
+        Ok(())
+    } // draws the toolbar along the bottom of the screen & applies any clicks on its widgets
+
+    fn inspector_area(&self) -> Rect {
+        let area = renderer::editor_area(self.draw_mode);
+        Rect::new(
+            area.right() - (SCREEN_WIDTH / 4) as i32,
+            area.y(),
+            (SCREEN_WIDTH / 4) as u32,
+            6 * area.height() / 7,
+        )
+    } // where the inspector panels are drawn, along the right edge of the editor
+
+    pub fn draw_entities(&mut self, grid: &Grid, player: &PlayerInfo) -> Result<(), String> {
+        for (e, entity) in player.level.entities.iter().enumerate() {
+            let (x, y) = grid.screen_point(entity.x, entity.y);
+            let color = entity.kind.color();
+            for radius in 1..6 {
+                self.draw_circle(x, y, radius, color)?;
+            }
+            self.draw_line(
+                x,
+                y,
+                x + 12.0 * sine(entity.angle),
+                y + 12.0 * cosine(entity.angle),
+                color,
+            )?; // points the way the entity is facing, like the player's arc
+            if grid.selected_entity == Some(e) {
+                self.draw_circle(x, y, 8, colors::WHITE)?;
+            }
+        }
+        Ok(())
+    } // draws every entity in the level as a colored dot
+
+    pub fn draw_entity_inspector(
+        &mut self,
+        grid: &mut Grid,
         player: &mut PlayerInfo,
+        font: &sdl2::ttf::Font,
+    ) -> Result<(), String> {
+        let e = match grid.selected_entity {
+            Some(e) if e < player.level.entities.len() => e,
+            _ => return Ok(()),
+        };
+        let ui = &mut grid.ui;
+        let inspector = self.inspector_area();
+        ui::panel(self, ui, inspector, colors::GREY4)?;
+        let mut layout = ui::Layout::new(inspector, 28);
+        layout.column(0.95);
+        let (caption, field) = layout.form();
+        let before = player.level.entities[e].clone();
+        let entity = &mut player.level.entities[e];
+
+        ui::label(
+            self,
+            &mut layout,
+            font,
+            &format!("Entity:{}", e),
+            colors::WHITE,
+        )?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "kind", caption)?;
+        let kinds: Vec<&str> = EntityKind::ALL.iter().map(|kind| kind.name()).collect();
+        let kind = EntityKind::ALL
+            .iter()
+            .position(|kind| *kind == entity.kind)
+            .unwrap_or(0);
+        entity.kind = EntityKind::ALL[ui::dropdown(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "kind",
+            ui::Choice {
+                options: &kinds,
+                selected: kind,
+            },
+            field,
+        )?];
+        layout.row();
+        ui::caption(self, &mut layout, font, "x", caption)?;
+        entity.x = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "entity x",
+            entity.x,
+            ui::Steps {
+                step: 1.0,
+                big_step: 8.0,
+            },
+        )?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "y", caption)?;
+        entity.y = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "entity y",
+            entity.y,
+            ui::Steps {
+                step: 1.0,
+                big_step: 8.0,
+            },
+        )?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "z", caption)?;
+        entity.z = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "entity z",
+            entity.z,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "angle", caption)?;
+        entity.angle = (ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "entity angle",
+            entity.angle as f32,
+            ui::Steps {
+                step: 15.0,
+                big_step: 90.0,
+            },
+        )? as i32)
+            .rem_euclid(360);
+        layout.row();
+
+        ui::caption(self, &mut layout, font, "properties", caption)?;
+        match ui::button(self, ui, &mut layout, font, "+")? {
+            Some(_) => entity.properties.push((String::new(), String::new())),
+            _ => {}
+        }
+        layout.row();
+        let half = (layout.column_width - 2 * layout.padding as u32 - layout.row_height) / 2;
+        let mut removed = None;
+        for (p, (key, value)) in entity.properties.iter_mut().enumerate() {
+            *key = ui::text_field(
+                self,
+                ui,
+                &mut layout,
+                font,
+                &format!("property key {}", p),
+                key,
+                half,
+            )?;
+            *value = ui::text_field(
+                self,
+                ui,
+                &mut layout,
+                font,
+                &format!("property value {}", p),
+                value,
+                half,
+            )?;
+            match ui::button(self, ui, &mut layout, font, "x")? {
+                Some(_) => removed = Some(p),
+                _ => {}
+            }
+            layout.row();
+        } // every property is a key & a value that can be typed into
+        match removed {
+            Some(p) => {
+                entity.properties.remove(p);
+            }
+            _ => {}
+        }
+        if player.level.entities[e] != before {
+            player.level.spawn();
+        } // the preview shows the entity as it is now
+        Ok(())
+    } // draws a panel with every property of the selected entity which can all be edited
+
+    fn wall_texture_fields(
+        &mut self,
+        ui: &mut ui::Ui,
+        layout: &mut ui::Layout,
+        font: &sdl2::ttf::Font,
+        name: &str,
+        slot: WallTexture,
+    ) -> Result<WallTexture, String> {
+        let (caption, field) = layout.form();
+        ui::caption(self, layout, font, name, caption)?;
+        let texture = ui::dropdown(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} texture", name),
+            ui::Choice {
+                options: &texture_names(),
+                selected: texture_index(slot.texture),
+            },
+            field,
+        )?;
+        layout.row();
+        ui::caption(self, layout, font, &format!("{} u", name), caption)?;
+        let u = ui::spinner(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} u", name),
+            slot.u,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )?;
+        layout.row();
+        ui::caption(self, layout, font, &format!("{} v", name), caption)?;
+        let v = ui::spinner(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} v", name),
+            slot.v,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )?;
+        layout.row();
+        Ok(WallTexture {
+            texture: texture_from_index(texture),
+            u,
+            v,
+        })
+    } // a texture with its own u & v; returns the edited values so the caller can store them
+
+    fn trigger_fields(
+        &mut self,
+        ui: &mut ui::Ui,
+        layout: &mut ui::Layout,
+        font: &sdl2::ttf::Font,
+        id: &str,
+        tag: u32,
+        trigger: Option<Trigger>,
+    ) -> Result<(u32, Option<Trigger>), String> {
+        let (caption, field) = layout.form();
+        ui::caption(self, layout, font, "tag", caption)?;
+        let tag = ui::spinner(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} tag", id),
+            tag as f32,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )?
+        .max(0.0) as u32;
+        layout.row();
+        let events = [
+            TriggerEvent::Use,
+            TriggerEvent::Cross,
+            TriggerEvent::Enter,
+            TriggerEvent::Shoot,
+        ];
+        ui::caption(self, layout, font, "trigger on", caption)?;
+        let event = ui::dropdown(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} event", id),
+            ui::Choice {
+                options: &["None", "Use", "Cross", "Enter", "Shoot"],
+                selected: match trigger {
+                    Some(trigger) => trigger.event as usize + 1,
+                    _ => 0,
+                },
+            },
+            field,
+        )?;
+        let mut trigger = match (event, trigger) {
+            (0, _) => return Ok((tag, None)),
+            (_, Some(trigger)) => trigger,
+            _ => Trigger {
+                event: TriggerEvent::Use,
+                action: Action::MoveSector { height: 0 },
+                target: tag,
+                once: false,
+            }, // a new trigger starts out aimed at its own tag
+        };
+        trigger.event = events[event - 1];
+
+        layout.row();
+        ui::caption(self, layout, font, "action", caption)?;
+        let action = ui::dropdown(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} action", id),
+            ui::Choice {
+                options: &Action::NAMES,
+                selected: trigger.action.index(),
+            },
+            field,
+        )?;
+        if action != trigger.action.index() {
+            trigger.action = Action::from_index(action);
+        }
+        trigger.action = match trigger.action {
+            Action::MoveSector { height } => {
+                layout.row();
+                ui::caption(self, layout, font, "height", caption)?;
+                let height = ui::spinner(
+                    self,
+                    ui,
+                    layout,
+                    font,
+                    &format!("{} height", id),
+                    height as f32,
+                    ui::Steps {
+                        step: 1.0,
+                        big_step: 10.0,
+                    },
+                )? as i32;
+                Action::MoveSector { height }
+            }
+            Action::ChangeTexture { texture } => {
+                layout.row();
+                ui::caption(self, layout, font, "texture", caption)?;
+                let texture = ui::dropdown(
+                    self,
+                    ui,
+                    layout,
+                    font,
+                    &format!("{} new texture", id),
+                    ui::Choice {
+                        options: &texture_names(),
+                        selected: texture_index(texture),
+                    },
+                    field,
+                )?;
+                Action::ChangeTexture {
+                    texture: texture_from_index(texture),
+                }
+            }
+            Action::Teleport { x, y, z } => {
+                let mut position = [x, y, z];
+                for (name, value) in ["x", "y", "z"].iter().zip(position.iter_mut()) {
+                    layout.row();
+                    ui::caption(self, layout, font, name, caption)?;
+                    *value = ui::spinner(
+                        self,
+                        ui,
+                        layout,
+                        font,
+                        &format!("{} teleport {}", id, name),
+                        *value as f32,
+                        ui::Steps {
+                            step: 1.0,
+                            big_step: 8.0,
+                        },
+                    )? as i32;
+                }
+                Action::Teleport {
+                    x: position[0],
+                    y: position[1],
+                    z: position[2],
+                }
+            }
+            Action::EndLevel => Action::EndLevel,
+            Action::PlaySound { sound } => {
+                layout.row();
+                ui::caption(self, layout, font, "sound", caption)?;
+                let sound = ui::dropdown(
+                    self,
+                    ui,
+                    layout,
+                    font,
+                    &format!("{} sound", id),
+                    ui::Choice {
+                        options: &audio::SOUND_NAMES,
+                        selected: (sound as usize).min(audio::SOUND_NAMES.len() - 1),
+                    },
+                    field,
+                )? as u32;
+                Action::PlaySound { sound }
+            }
+        }; // each action has its own settings
+
+        layout.row();
+        ui::caption(self, layout, font, "target tag", caption)?;
+        trigger.target = ui::spinner(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} target", id),
+            trigger.target as f32,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )?
+        .max(0.0) as u32;
+        layout.row();
+        ui::caption(self, layout, font, "fires", caption)?;
+        trigger.once = ui::dropdown(
+            self,
+            ui,
+            layout,
+            font,
+            &format!("{} once", id),
+            ui::Choice {
+                options: &["Every time", "Once"],
+                selected: trigger.once as usize,
+            },
+            field,
+        )? == 1;
+        Ok((tag, Some(trigger)))
+    } // draws the fields for a wall's or sector's tag & trigger
+
+    pub fn draw_inspector(
+        &mut self,
         grid: &mut Grid,
+        player: &mut PlayerInfo,
         font: &sdl2::ttf::Font,
     ) -> Result<(), String> {
-        self.draw_mode = renderer::DrawMode::Draw2D;
-        grid.scale = no_less_than_one(grid.scale);
-        let scale_width = SCREEN_WIDTH / 160 * grid.scale as usize;
-        let scale_height = SCREEN_HEIGHT / 120 * grid.scale as usize;
-
-        //draw grid
-        for x in 0..SCREEN_WIDTH as usize {
-            let grid_x = x * scale_width;
-            for y in 0..1 * SCREEN_HEIGHT {
-                self.draw_dot(grid_x as f32, y as f32, colors::BLACK)?;
+        let (s, w) = match (grid.selected_sector, grid.selected_wall) {
+            (Some(s), Some(w)) => (s, w),
+            _ => return Ok(()),
+        };
+        let ui = &mut grid.ui;
+        let inspector = self.inspector_area();
+        ui::panel(self, ui, inspector, colors::GREY4)?;
+        let mut layout = ui::Layout::new(inspector, 28);
+        layout.column(0.95);
+        let (caption, field) = layout.form();
+        let texture_names = texture_names();
+
+        for (name, tab) in [
+            ("Properties", InspectorTab::Properties),
+            ("Triggers", InspectorTab::Triggers),
+            ("Textures", InspectorTab::Textures),
+        ] {
+            if ui::tab(self, ui, &mut layout, font, name, grid.inspector_tab == tab)? {
+                grid.inspector_tab = tab;
             }
         }
-        for y in 0..SCREEN_HEIGHT as usize {
-            let grid_y = y * scale_height;
-            for x in 0..1 * SCREEN_WIDTH {
-                self.draw_dot(x as f32, grid_y as f32, colors::BLACK)?;
+        layout.row();
+        if grid.inspector_tab == InspectorTab::Triggers {
+            for (name, id) in [
+                (format!("Sector:{}", s), "sector"),
+                (format!("Wall:{}", w), "wall"),
+            ] {
+                ui::label(self, &mut layout, font, &name, colors::WHITE)?;
+                layout.row();
+                match id {
+                    "sector" => {
+                        let sector = &mut player.level.sectors[s];
+                        (sector.tag, sector.trigger) = self.trigger_fields(
+                            ui,
+                            &mut layout,
+                            font,
+                            id,
+                            sector.tag,
+                            sector.trigger,
+                        )?;
+                    }
+                    _ => {
+                        let wall = &mut player.level.walls[w];
+                        (wall.tag, wall.trigger) =
+                            self.trigger_fields(ui, &mut layout, font, id, wall.tag, wall.trigger)?;
+                    }
+                }
+                layout.row();
             }
+            return Ok(());
+        } // the sector's & wall's triggers have a page to themselves
+        if grid.inspector_tab == InspectorTab::Textures {
+            ui::label(
+                self,
+                &mut layout,
+                font,
+                &format!("Wall:{}", w),
+                colors::WHITE,
+            )?;
+            layout.row();
+            let mut wall = player.level.walls[w];
+            wall.upper = self.wall_texture_fields(ui, &mut layout, font, "upper", wall.upper)?;
+            let middle =
+                self.wall_texture_fields(ui, &mut layout, font, "middle", wall.middle())?;
+            (wall.texture, wall.u, wall.v) = (middle.texture, middle.u, middle.v);
+            wall.lower = self.wall_texture_fields(ui, &mut layout, font, "lower", wall.lower)?;
+            ui::caption(self, &mut layout, font, "offset x", caption)?;
+            wall.offset_x = ui::spinner(
+                self,
+                ui,
+                &mut layout,
+                font,
+                "offset x",
+                wall.offset_x,
+                ui::Steps {
+                    step: 0.125,
+                    big_step: 0.5,
+                },
+            )?;
+            layout.row();
+            ui::caption(self, &mut layout, font, "offset y", caption)?;
+            wall.offset_y = ui::spinner(
+                self,
+                ui,
+                &mut layout,
+                font,
+                "offset y",
+                wall.offset_y,
+                ui::Steps {
+                    step: 0.125,
+                    big_step: 0.5,
+                },
+            )?;
+            layout.row();
+            ui::caption(self, &mut layout, font, "mapping", caption)?;
+            wall.world_aligned = ui::dropdown(
+                self,
+                ui,
+                &mut layout,
+                font,
+                "mapping",
+                ui::Choice {
+                    options: &["Wall", "World"],
+                    selected: wall.world_aligned as usize,
+                },
+                field,
+            )? == 1;
+            layout.row();
+            player.level.walls[w] = wall;
+            ui::caption(self, &mut layout, font, "align walls", caption)?;
+            match ui::button(self, ui, &mut layout, font, "=")? {
+                Some(_) => player.level.align_walls(w),
+                _ => {}
+            } // lines up every texture in the sector's loop with the one before it
+            layout.row();
+            match wall.texture {
+                Some(texture) => {
+                    let size = 3 * layout.row_height;
+                    let preview = layout.next(size, size);
+                    match ui::texture_button(self, ui, preview, texture)? {
+                        Some(_) if grid.browser.is_none() => grid.toggle_browser(),
+                        _ => {}
+                    } // clicking on the preview opens the texture browser
+                }
+                _ => {}
+            }
+            return Ok(());
+        } // upper & lower textures only show where a neighbouring sector is shorter or taller
+
+        let sector = &mut player.level.sectors[s];
+        ui::label(
+            self,
+            &mut layout,
+            font,
+            &format!("Sector:{}", s),
+            colors::WHITE,
+        )?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "walls", caption)?;
+        ui::label(
+            self,
+            &mut layout,
+            font,
+            &format!("{}..{}", sector.wall_start, sector.wall_end),
+            colors::BLACK,
+        )?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "floor_z", caption)?;
+        sector.bottom_height = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "floor_z",
+            sector.bottom_height as f32,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )? as i32;
+        layout.row();
+        ui::caption(self, &mut layout, font, "ceiling_z", caption)?;
+        sector.top_height = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "ceiling_z",
+            sector.top_height as f32,
+            ui::Steps {
+                step: 1.0,
+                big_step: 10.0,
+            },
+        )? as i32;
+        layout.row();
+        ui::caption(self, &mut layout, font, "light", caption)?;
+        sector.light = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "light",
+            sector.light as f32,
+            ui::Steps {
+                step: 8.0,
+                big_step: 32.0,
+            },
+        )?
+        .clamp(0.0, 255.0) as u8;
+        layout.row();
+        ui::caption(self, &mut layout, font, "floor color", caption)?;
+        sector.bottom_color =
+            ui::color_picker(self, ui, &mut layout, "floor color", sector.bottom_color)?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "ceiling color", caption)?;
+        sector.top_color =
+            ui::color_picker(self, ui, &mut layout, "ceiling color", sector.top_color)?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "ceiling", caption)?;
+        sector.sky = ui::dropdown(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "ceiling",
+            ui::Choice {
+                options: &["Solid", "Sky"],
+                selected: sector.sky as usize,
+            },
+            field,
+        )? == 1;
+        layout.row();
+        ui::caption(self, &mut layout, font, "surface", caption)?;
+        let surface = ui::dropdown(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "surface",
+            ui::Choice {
+                options: &texture_names,
+                selected: texture_index(sector.surface_texture),
+            },
+            field,
+        )?;
+        sector.surface_texture = texture_from_index(surface);
+        layout.row();
+        ui::caption(self, &mut layout, font, "mover", caption)?;
+        let kind = ui::dropdown(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "mover",
+            ui::Choice {
+                options: &["None", "Door", "Lift", "Crusher"],
+                selected: match sector.mover {
+                    Some(mover) => mover.kind as usize + 1,
+                    _ => 0,
+                },
+            },
+            field,
+        )?;
+        sector.mover = match (kind, sector.mover) {
+            (0, _) => None,
+            (_, mover) => {
+                let mut mover = mover.unwrap_or(Mover {
+                    kind: MoverKind::Door,
+                    activation: Activation::Use,
+                    bottom: sector.top_height,
+                    top: sector.top_height,
+                    speed: 1,
+                    wait: 60,
+                }); // a new mover starts out as a door that opens all the way
+                mover.kind = [MoverKind::Door, MoverKind::Lift, MoverKind::Crusher][kind - 1];
+                Some(mover)
+            }
+        };
+        match sector.mover.as_mut() {
+            Some(mover) => {
+                layout.row();
+                ui::caption(self, &mut layout, font, "set off by", caption)?;
+                mover.activation = match ui::dropdown(
+                    self,
+                    ui,
+                    &mut layout,
+                    font,
+                    "set off by",
+                    ui::Choice {
+                        options: &["Use", "Enter"],
+                        selected: mover.activation as usize,
+                    },
+                    field,
+                )? {
+                    0 => Activation::Use,
+                    _ => Activation::Enter,
+                };
+                layout.row();
+                ui::caption(self, &mut layout, font, "floor to", caption)?;
+                mover.bottom = ui::spinner(
+                    self,
+                    ui,
+                    &mut layout,
+                    font,
+                    "floor to",
+                    mover.bottom as f32,
+                    ui::Steps {
+                        step: 1.0,
+                        big_step: 10.0,
+                    },
+                )? as i32;
+                layout.row();
+                ui::caption(self, &mut layout, font, "ceiling to", caption)?;
+                mover.top = ui::spinner(
+                    self,
+                    ui,
+                    &mut layout,
+                    font,
+                    "ceiling to",
+                    mover.top as f32,
+                    ui::Steps {
+                        step: 1.0,
+                        big_step: 10.0,
+                    },
+                )? as i32;
+                layout.row();
+                ui::caption(self, &mut layout, font, "speed", caption)?;
+                mover.speed = (ui::spinner(
+                    self,
+                    ui,
+                    &mut layout,
+                    font,
+                    "speed",
+                    mover.speed as f32,
+                    ui::Steps {
+                        step: 1.0,
+                        big_step: 5.0,
+                    },
+                )? as i32)
+                    .max(1);
+                layout.row();
+                ui::caption(self, &mut layout, font, "wait", caption)?;
+                mover.wait = ui::spinner(
+                    self,
+                    ui,
+                    &mut layout,
+                    font,
+                    "wait",
+                    mover.wait as f32,
+                    ui::Steps {
+                        step: 10.0,
+                        big_step: 60.0,
+                    },
+                )?
+                .max(0.0) as u32;
+            }
+            _ => {}
+        } // doors, lifts & crushers only need these when the sector is one
+        layout.row();
+        layout.row();
+
+        let wall = player.level.walls[w];
+        ui::label(
+            self,
+            &mut layout,
+            font,
+            &format!("Wall:{}", w),
+            colors::WHITE,
+        )?;
+        for (name, x, y) in [("x1", wall.x1, wall.y1), ("x2", wall.x2, wall.y2)] {
+            layout.row();
+            ui::caption(self, &mut layout, font, name, caption)?;
+            let new_x = ui::number_field(self, ui, &mut layout, font, name, x, field)?;
+            layout.row();
+            let y_name = name.replace('x', "y");
+            ui::caption(self, &mut layout, font, &y_name, caption)?;
+            let new_y = ui::number_field(self, ui, &mut layout, font, &y_name, y, field)?;
+            if (new_x, new_y) != (x, y) {
+                Grid::move_vertex(player, (x, y), (new_x, new_y));
+            } // moves the point on every wall that shares it so the sector stays closed
         }
 
+        let wall = &mut player.level.walls[w];
+        layout.row();
+        ui::caption(self, &mut layout, font, "color", caption)?;
+        wall.color = ui::color_picker(self, ui, &mut layout, "wall color", wall.color)?;
+        layout.row();
+        ui::caption(self, &mut layout, font, "masked", caption)?;
+        wall.masked = ui::dropdown(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "masked",
+            ui::Choice {
+                options: &["No", "Yes"],
+                selected: wall.masked as usize,
+            },
+            field,
+        )? == 1;
+        layout.row();
+        ui::caption(self, &mut layout, font, "opacity", caption)?;
+        wall.opacity = ui::spinner(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "opacity",
+            wall.opacity as f32,
+            ui::Steps {
+                step: 8.0,
+                big_step: 32.0,
+            },
+        )?
+        .clamp(0.0, 255.0) as u8;
+        layout.row();
+        ui::caption(self, &mut layout, font, "blocks", caption)?;
+        wall.blocking = ui::dropdown(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "blocks",
+            ui::Choice {
+                options: &["No", "Yes"],
+                selected: wall.blocking as usize,
+            },
+            field,
+        )? == 1;
+        Ok(())
+    } // draws a panel with every property of the selected sector & wall which can all be edited
+
+    pub fn draw_texture_browser(
+        &mut self,
+        grid: &mut Grid,
+        player: &mut PlayerInfo,
+        font: &sdl2::ttf::Font,
+    ) -> Result<(), String> {
+        let mut browser = match grid.browser.clone() {
+            Some(browser) => browser,
+            _ => return Ok(()),
+        };
+        let ui = &mut grid.ui;
+        let area = Rect::new(
+            0,
+            0,
+            (SCREEN_WIDTH / 4) as u32,
+            (6 * SCREEN_HEIGHT / 7) as u32,
+        );
+        ui::panel(self, ui, area, colors::GREY4)?;
+        let mut layout = ui::Layout::new(area, 20);
+        layout.column(0.95);
+
+        ui::label(self, &mut layout, font, "Textures", colors::WHITE)?;
+        let filter_width = layout.remaining();
+        browser.filter = ui::text_field(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "texture filter",
+            &browser.filter,
+            filter_width,
+        )?;
+        layout.row();
+        if ui::tab(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "Walls",
+            browser.target == BrowserTarget::Walls,
+        )? {
+            browser.target = BrowserTarget::Walls;
+        }
+        if ui::tab(
+            self,
+            ui,
+            &mut layout,
+            font,
+            "Surface",
+            browser.target == BrowserTarget::Surface,
+        )? {
+            browser.target = BrowserTarget::Surface;
+        }
+        layout.row();
+
+        let filter = browser.filter.to_uppercase();
+        let matches: Vec<Texture> = textures::TEXTURES
+            .iter()
+            .filter(|texture| texture.name.contains(&filter))
+            .copied()
+            .collect();
+        let columns = 3;
+        let cell = (layout.column_width - (columns - 1) * layout.padding as u32) / columns;
+        let name_height = layout.row_height / 2;
+        let top = layout.y;
+        let visible_rows =
+            ((area.bottom() - top) / (cell + name_height + layout.padding as u32) as i32).max(1);
+        let total_rows = (matches.len() as i32 + columns as i32 - 1) / columns as i32;
+        let grid_area = Rect::new(area.x(), top, area.width(), (area.bottom() - top) as u32);
+        browser.scroll = (browser.scroll - ui.scrolled(grid_area))
+            .min(total_rows - visible_rows)
+            .max(0); // the mouse wheel scrolls by one row of thumbnails
+
+        let texture_creator = self.canvas.texture_creator();
+        let mut chosen = None;
+        for (i, texture) in matches
+            .iter()
+            .enumerate()
+            .skip((browser.scroll * columns as i32) as usize)
+            .take((visible_rows * columns as i32) as usize)
+        {
+            let row = i as i32 / columns as i32 - browser.scroll;
+            let column = i as u32 % columns;
+            let thumbnail = Rect::new(
+                layout.column_x + (column * (cell + layout.padding as u32)) as i32,
+                top + row * (cell + name_height + layout.padding as u32) as i32,
+                cell,
+                cell,
+            );
+            let preview = get_texture(
+                &texture_creator,
+                texture.width,
+                texture.height,
+                texture.data,
+            )?;
+            self.canvas.copy(&preview, None, thumbnail)?;
+            let current = match browser.target {
+                BrowserTarget::Walls => match grid.selected_wall {
+                    Some(wall) => player.level.walls[wall].texture == Some(*texture),
+                    _ => false,
+                },
+                BrowserTarget::Surface => match grid.selected_sector {
+                    Some(sector) => player.level.sectors[sector].surface_texture == Some(*texture),
+                    _ => false,
+                },
+            };
+            self.canvas
+                .set_draw_color(match (current, ui.hovered(thumbnail)) {
+                    (true, _) => colors::YELLOW,
+                    (false, true) => colors::WHITE,
+                    (false, false) => colors::BLACK,
+                });
+            self.canvas.draw_rect(thumbnail)?;
+            let name = Rect::new(thumbnail.x(), thumbnail.bottom(), cell, name_height);
+            ui::fit_text(
+                self,
+                font,
+                texture.name.trim_end_matches("_TEXTURE"),
+                colors::BLACK,
+                name,
+            )?;
+            if ui.clicked(thumbnail).is_some() {
+                chosen = Some(*texture);
+            }
+        }
+        match chosen {
+            Some(texture) => grid.apply_texture(player, browser.target, texture),
+            _ => {}
+        } // one click gives the texture to everything that is selected
+
+        grid.browser = Some(browser);
+        Ok(())
+    } // draws a scrollable list of every texture that can be filtered by name & clicked on to use it
+
+    pub fn draw2d(
+        // Master function for the the level builder;
+        &mut self, // Draws a 2D representation of the sectors & walls in level.rs
+        player: &mut PlayerInfo,
+        grid: &mut Grid,
+        font: &sdl2::ttf::Font,
+    ) -> Result<(), String> {
+        grid.ui
+            .begin(grid.mouse_status.mouse_x, grid.mouse_status.mouse_y);
+        self.draw_grid(grid)?;
+
         //draw sectors
         for s in 0..player.level.number_of_sectors as usize {
             for wall in player.level.sectors[s as usize].wall_start
                 ..player.level.sectors[s as usize].wall_end
             {
                 self.draw_thick_line(
-                    (player.level.walls[wall as usize].x1 + grid.view_shift_x as f32)
-                        * grid.scale as f32,
-                    (player.level.walls[wall as usize].y1 + grid.view_shift_y as f32)
-                        * grid.scale as f32,
-                    (player.level.walls[wall as usize].x2 + grid.view_shift_x as f32)
-                        * grid.scale as f32,
-                    (player.level.walls[wall as usize].y2 + grid.view_shift_y as f32)
-                        * grid.scale as f32,
+                    (player.level.walls[wall as usize].x1 + grid.view_shift_x) * grid.scale,
+                    (player.level.walls[wall as usize].y1 + grid.view_shift_y) * grid.scale,
+                    (player.level.walls[wall as usize].x2 + grid.view_shift_x) * grid.scale,
+                    (player.level.walls[wall as usize].y2 + grid.view_shift_y) * grid.scale,
                     player.level.walls[wall as usize].color,
                 )?; // Draw walls
 
-                // circle selected points from the drawn highlight
-                if grid.selected_point.is_none() {
-                    for point in grid.selection.points.clone() {
-                        if (point as f32 / 2.0).fract() == 0.5 {
-                            self.draw_circle(
-                                (player.level.walls[(point as usize + 1) / 2].x1
-                                    + grid.view_shift_x as f32)
-                                    * grid.scale as f32,
-                                (player.level.walls[(point as usize + 1) / 2].y1
-                                    + grid.view_shift_y as f32)
-                                    * grid.scale as f32,
-                                4,
-                                colors::WHITE,
-                            )?; // circle all points in selection and keep them circled until another click
-                        }
-                    }
-                }
-                if grid.highlight_x.is_some() && grid.selected_point.is_none() {
-                    let highghlight = self.highlight_rectangle(
-                        grid,
-                        grid.highlight_x.unwrap(),
-                        grid.highlight_y.unwrap(),
-                        false,
-                    )?; // get the highlighted area
-
-                    if highghlight.contains_point((
-                        ((player.level.walls[wall as usize].x1 + grid.view_shift_x as f32)
-                            * grid.scale as f32) as i32,
-                        ((player.level.walls[wall as usize].y1 + grid.view_shift_y as f32)
-                            * grid.scale as f32) as i32,
-                    )) {
-                        if wall > 0 {
-                            let point_number = wall as usize + wall as usize - 1;
-                            grid.selection.points.push(point_number); // if point1 is highlighted add it to selection.points
-                        }
-                    }
-                    if highghlight.contains_point((
-                        ((player.level.walls[wall as usize].x2 + grid.view_shift_x as f32)
-                            * grid.scale as f32) as i32,
-                        ((player.level.walls[wall as usize].y2 + grid.view_shift_y as f32)
-                            * grid.scale as f32) as i32,
-                    )) {
-                        let point_number = wall as usize + wall as usize;
-                        grid.selection.points.push(point_number); // if point2 is highlighted add it to selection.points
-                    }
-                }
-
                 if grid.selected_wall.is_some() {
                     if wall == grid.selected_wall.unwrap() as i32 {
                         grid.selected_sector = Some(s);
                         self.draw_thick_line(
-                            (player.level.walls[wall as usize].x1 + grid.view_shift_x as f32)
-                                * grid.scale as f32,
-                            (player.level.walls[wall as usize].y1 + grid.view_shift_y as f32)
-                                * grid.scale as f32,
-                            (player.level.walls[wall as usize].x2 + grid.view_shift_x as f32)
-                                * grid.scale as f32,
-                            (player.level.walls[wall as usize].y2 + grid.view_shift_y as f32)
-                                * grid.scale as f32,
+                            (player.level.walls[wall as usize].x1 + grid.view_shift_x) * grid.scale,
+                            (player.level.walls[wall as usize].y1 + grid.view_shift_y) * grid.scale,
+                            (player.level.walls[wall as usize].x2 + grid.view_shift_x) * grid.scale,
+                            (player.level.walls[wall as usize].y2 + grid.view_shift_y) * grid.scale,
                             colors::WHITE,
                         )?; // Draw walls
                     }
@@ -722,19 +2275,15 @@ impl renderer::Renderer {
                 if distance(
                     grid.mouse_status.mouse_x as f32,
                     grid.mouse_status.mouse_y as f32,
-                    (player.level.walls[wall as usize].x2 + grid.view_shift_x as f32)
-                        * grid.scale as f32,
-                    (player.level.walls[wall as usize].y2 + grid.view_shift_y as f32)
-                        * grid.scale as f32,
+                    (player.level.walls[wall as usize].x2 + grid.view_shift_x) * grid.scale,
+                    (player.level.walls[wall as usize].y2 + grid.view_shift_y) * grid.scale,
                 ) <= 6.0
                     && grid.highlight_x.is_none()
                 {
                     for i in 1..6 {
                         self.draw_circle(
-                            (player.level.walls[wall as usize].x2 + grid.view_shift_x as f32)
-                                * grid.scale as f32,
-                            (player.level.walls[wall as usize].y2 + grid.view_shift_y as f32)
-                                * grid.scale as f32,
+                            (player.level.walls[wall as usize].x2 + grid.view_shift_x) * grid.scale,
+                            (player.level.walls[wall as usize].y2 + grid.view_shift_y) * grid.scale,
                             i,
                             colors::WHITE,
                         )?; // point mouse-over  animation
@@ -743,9 +2292,28 @@ impl renderer::Renderer {
             }
         }
 
+        // outline the selected walls & circle the selected points until another click
+        if grid.selected_point.is_none() {
+            for wall in grid.selection.walls.clone() {
+                let (x1, y1) =
+                    grid.screen_point(player.level.walls[wall].x1, player.level.walls[wall].y1);
+                let (x2, y2) =
+                    grid.screen_point(player.level.walls[wall].x2, player.level.walls[wall].y2);
+                self.draw_thick_line(x1, y1, x2, y2, colors::LIGHT_YELLOW)?;
+            }
+            for point in grid.selected_endpoints(player) {
+                let (x, y) = endpoint(player, point);
+                let (x, y) = grid.screen_point(x, y);
+                self.draw_circle(x, y, 4, colors::WHITE)?;
+            }
+        }
+
+        self.draw_entities(grid, player)?;
+        let (player_x, player_y) =
+            grid.screen_point(player.position.x as f32, player.position.y as f32);
         self.draw_player(
-            (player.position.x + grid.view_shift_x) * grid.scale as i32,
-            (player.position.y + grid.view_shift_y) * grid.scale as i32,
+            player_x as i32,
+            player_y as i32,
             colors::GREEN,
             grid,
             player,
@@ -755,253 +2323,12 @@ impl renderer::Renderer {
             self.draw_new_sector(grid, player)?;
         }
 
-        let bar = (6 * SCREEN_HEIGHT) / 7;
-        for y in bar..SCREEN_HEIGHT {
-            for x in 0..SCREEN_WIDTH {
-                self.draw_dot(x as f32, y as f32, colors::GREY4)?;
-            }
-        } //draw background for toolbar
-
-        let texture_creator = self.canvas.texture_creator();
-        //draw text and buttons:
-        let screen_x =
-            ((grid.mouse_status.mouse_x) as f32 / (grid.scale as f32)) - grid.view_shift_x as f32;
-        let screen_y =
-            ((grid.mouse_status.mouse_y) as f32 / (grid.scale as f32)) - grid.view_shift_y as f32;
-        let mouse_x_text = format!("Mouse x: {:?}", screen_x).to_string();
-        let mouse_y_text = format!("Mouse y: {:?}", screen_y).to_string();
-
-        if grid.selected_sector.is_some() {
-            let sector_text = format!("Sector:{:#?}", grid.selected_sector.unwrap()).to_string();
-            let wall_text = format!("Wall:{:#?}", grid.selected_wall.unwrap()).to_string();
-            let wall_u_text = format!(
-                "texture u:{}",
-                player.level.walls[grid.selected_wall.unwrap()].u
-            );
-            let wall_v_text = format!(
-                "texture v:{}",
-                player.level.walls[grid.selected_wall.unwrap()].v
-            );
-            let floor_height_text = format!(
-                "floor_z:{:#?}",
-                player.level.sectors[grid.selected_sector.unwrap() as usize].bottom_height
-            )
-            .to_string();
-            let ceiling_height_text = format!(
-                "ceiling_z:{:#?}",
-                player.level.sectors[grid.selected_sector.unwrap() as usize].top_height
-            )
-            .to_string();
-
-            let texture_preview_rect = Rect::new(445, 735, 90, 90);
-            self.canvas.set_draw_color(colors::RED);
-            self.canvas.fill_rect(texture_preview_rect)?;
-            let texture = get_texture(
-                &texture_creator,
-                player.level.walls[grid.selected_wall.unwrap() as usize]
-                    .texture
-                    .unwrap()
-                    .width,
-                player.level.walls[grid.selected_wall.unwrap() as usize]
-                    .texture
-                    .unwrap()
-                    .height,
-                player.level.walls[grid.selected_wall.unwrap() as usize]
-                    .texture
-                    .unwrap()
-                    .data,
-            )?;
-            self.canvas.copy(&texture, None, texture_preview_rect)?;
-            self.canvas.set_draw_color(colors::BLACK);
-            for i in 0..3 {
-                self.canvas.draw_rect(Rect::new(
-                    444 - i,
-                    734 - i,
-                    (91 + i * 2) as u32,
-                    (91 + i * 2) as u32,
-                ))?;
-            }
-
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                wall_text,
-                colors::BLACK,
-                240,
-                740,
-                110,
-                25,
-            )?; // wall number text
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                wall_u_text,
-                colors::BLACK,
-                240,
-                770,
-                120,
-                20,
-            )?; // wall u text
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "+".to_string(),
-                colors::BLACK,
-                370,
-                770,
-                25,
-                25,
-            )?; // wall u plus
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "-".to_string(),
-                colors::BLACK,
-                400,
-                760,
-                25,
-                40,
-            )?; // wall u minus
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                wall_v_text,
-                colors::BLACK,
-                240,
-                800,
-                120,
-                20,
-            )?; // wall v text
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "+".to_string(),
-                colors::BLACK,
-                370,
-                800,
-                25,
-                25,
-            )?; // wall v plus
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "-".to_string(),
-                colors::BLACK,
-                400,
-                795,
-                25,
-                40,
-            )?; // wall u minus
-
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                sector_text,
-                colors::BLACK,
-                25,
-                740,
-                140,
-                25,
-            )?; // sector number text
-
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                floor_height_text,
-                colors::BLACK,
-                25,
-                770,
-                120,
-                20,
-            )?; // sector floor text
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "+".to_string(),
-                colors::BLACK,
-                160,
-                770,
-                25,
-                25,
-            )?; // sector floor plus
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "-".to_string(),
-                colors::BLACK,
-                190,
-                765,
-                25,
-                40,
-            )?; // sector floor minus
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                ceiling_height_text,
-                colors::BLACK,
-                25,
-                790,
-                120,
-                20,
-            )?; // sector ceiling text
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "+".to_string(),
-                colors::BLACK,
-                160,
-                790,
-                25,
-                25,
-            )?; // sector cieling plus
-            Self::text(
-                self,
-                &texture_creator,
-                &font,
-                "-".to_string(),
-                colors::BLACK,
-                190,
-                785,
-                25,
-                40,
-            )?; // sector ceiling minus
-        }
-
-        Self::text(
-            self,
-            &texture_creator,
-            &font,
-            mouse_x_text,
-            colors::BLACK,
-            925,
-            740,
-            180,
-            30,
-        )?; // mouse x text
-        Self::text(
-            self,
-            &texture_creator,
-            &font,
-            mouse_y_text,
-            colors::BLACK,
-            925,
-            770,
-            180,
-            30,
-        )?; // mouse y text
+        self.draw_toolbar(grid, font)?;
+        match grid.selected_entity {
+            Some(_) => self.draw_entity_inspector(grid, player, font)?,
+            _ => self.draw_inspector(grid, player, font)?,
+        } // the selected entity takes the inspector's place
+        self.draw_texture_browser(grid, player, font)?;
 
         if grid.highlight_x.is_some() && grid.selected_point.is_none() {
             self.highlight_rectangle(
@@ -1012,6 +2339,7 @@ impl renderer::Renderer {
             )?;
         }
 
+        grid.ui.end();
         Ok(())
     }
 }
@@ -1031,7 +2359,7 @@ pub fn get_texture<'a>(
         .map_err(|e| e.to_string())?;
 
     texture.with_lock(None, |buffer: &mut [u8], _pitch: usize| {
-        for index in 0..4096 {
+        for index in 0..(texture_width * texture_height) as usize {
             let bytes = texture_data[index].to_be_bytes();
             buffer[index * 4] = bytes[0];
             buffer[index * 4 + 1] = bytes[1];
@@ -1042,3 +2370,40 @@ pub fn get_texture<'a>(
 
     Ok(texture)
 } // Creates a texture from a given array of u32s
+
+fn wall_texture_source(slot: WallTexture) -> String {
+    format!(
+        "WallTexture{{ texture:{}, u:{:?}, v:{:?} }}",
+        match slot.texture {
+            Some(texture) => format!("Some(textures::{})", texture.name),
+            _ => "None".to_string(),
+        },
+        slot.u,
+        slot.v
+    )
+} // writes an upper or lower texture out as rust
+
+pub fn texture_names() -> Vec<&'static str> {
+    let mut names = vec!["None"];
+    for texture in textures::TEXTURES.iter() {
+        names.push(texture.name.trim_end_matches("_TEXTURE"));
+    }
+    names
+} // the names shown in the editor's texture lists; the first option is no texture
+
+pub fn texture_index(texture: Option<Texture>) -> usize {
+    match texture {
+        Some(texture) => match textures::TEXTURES.iter().position(|t| *t == texture) {
+            Some(i) => i + 1,
+            _ => 0,
+        },
+        _ => 0,
+    }
+} // finds a texture's position in texture_names
+
+pub fn texture_from_index(index: usize) -> Option<Texture> {
+    match index {
+        0 => None,
+        _ => Some(textures::TEXTURES[index - 1]),
+    }
+} // the opposite of texture_index
@@ -0,0 +1,170 @@
+use crate::renderer::Renderer;
+use crate::*;
+use std::time::Instant;
+
+pub const HUD_TEXT_HEIGHT: u32 = (SCREEN_HEIGHT / 24) as u32; // how tall the numbers along the bottom are
+pub const HUD_MARGIN: i32 = (SCREEN_WIDTH / 64) as i32; // how far the readouts sit from the edges of the screen
+pub const CROSSHAIR_SIZE: i32 = (SCREEN_WIDTH / 160) as i32;
+pub const FRAME_SAMPLES: usize = 30; // how many frames the frame time is averaged over
+pub const MESSAGE_TIME: Duration = Duration::from_secs(3); // how long a message stays in the middle of the screen
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hud {
+    pub show_fps: bool,
+    pub last_frame: Option<Instant>,
+    pub frame_times: Vec<f32>, // how long the last few frames took in milliseconds
+    pub message: Option<(String, Instant)>, // shown until MESSAGE_TIME after it was sent
+}
+
+impl Default for Hud {
+    fn default() -> Hud {
+        Hud::new()
+    }
+}
+
+impl Hud {
+    pub fn new() -> Hud {
+        Hud {
+            show_fps: false,
+            last_frame: None,
+            frame_times: Vec::new(),
+            message: None,
+        }
+    }
+
+    pub fn show(&mut self, message: &str) {
+        self.message = Some((message.to_string(), Instant::now()));
+    } // puts a message in the middle of the screen for a few seconds
+
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        match self.last_frame {
+            Some(last_frame) => {
+                self.frame_times
+                    .push(now.duration_since(last_frame).as_secs_f32() * 1000.0);
+                if self.frame_times.len() > FRAME_SAMPLES {
+                    self.frame_times.remove(0);
+                }
+            }
+            _ => {}
+        }
+        self.last_frame = Some(now);
+    } // remembers how long it has been since the last frame
+
+    pub fn frame_time(&self) -> f32 {
+        self.frame_times.iter().sum::<f32>() / one_if_none(self.frame_times.len() as f32)
+    } // the average time a frame takes in milliseconds
+}
+
+impl Renderer {
+    pub fn draw_hud(&mut self, player: &PlayerInfo, font: &sdl2::ttf::Font) -> Result<(), String> {
+        self.hud.tick();
+        if !self.pick_mode {
+            self.draw_crosshair()?;
+        } // picking draws its own crosshair
+
+        let bottom = SCREEN_HEIGHT as i32 - HUD_TEXT_HEIGHT as i32 - HUD_MARGIN;
+        let health = format!("HEALTH {}", player.health.max(0));
+        let health_color = match player.health {
+            h if h <= PLAYER_HEALTH / 4 => colors::RED,
+            h if h <= PLAYER_HEALTH / 2 => colors::YELLOW,
+            _ => colors::WHITE,
+        }; // warns the player when they are close to running out
+        self.draw_readout(font, &health, health_color, HUD_MARGIN, bottom)?;
+
+        let weapon = player.weapons.weapon();
+        let ammo = format!(
+            "{}  {}",
+            weapon.name.to_uppercase(),
+            player.weapons.ammo[player.weapons.current]
+        );
+        let ammo_color = match player.weapons.ammo[player.weapons.current] {
+            0 => colors::RED,
+            _ => colors::WHITE,
+        };
+        let width = ui::text_width(font, &ammo, HUD_TEXT_HEIGHT)? as i32;
+        self.draw_readout(
+            font,
+            &ammo,
+            ammo_color,
+            SCREEN_WIDTH as i32 - width - HUD_MARGIN,
+            bottom,
+        )?;
+
+        if self.hud.show_fps {
+            let frame_time = self.hud.frame_time();
+            let fps = format!(
+                "{:.0} FPS  {:.1} MS",
+                1000.0 / one_if_none(frame_time),
+                frame_time
+            );
+            let width = ui::text_width(font, &fps, HUD_TEXT_HEIGHT / 2)? as i32;
+            ui::draw_text(
+                self,
+                font,
+                &fps,
+                colors::WHITE,
+                Rect::new(
+                    SCREEN_WIDTH as i32 - width - HUD_MARGIN,
+                    HUD_MARGIN,
+                    width as u32,
+                    HUD_TEXT_HEIGHT / 2,
+                ),
+            )?;
+        }
+
+        match self.hud.message.clone() {
+            Some((message, sent)) if sent.elapsed() < MESSAGE_TIME => {
+                let width = ui::text_width(font, &message, HUD_TEXT_HEIGHT)? as i32;
+                self.draw_readout(
+                    font,
+                    &message,
+                    colors::WHITE,
+                    HALF_WIDTH as i32 - width / 2,
+                    HALF_HEIGHT as i32 / 2,
+                )?;
+            }
+            _ => {}
+        }
+        Ok(())
+    } // draws health, ammo, a crosshair, any message & optionally the frame rate on top of the 3D view
+
+    fn draw_crosshair(&mut self) -> Result<(), String> {
+        let (x, y) = (HALF_WIDTH as i32, HALF_HEIGHT as i32);
+        self.canvas.set_draw_color(colors::WHITE);
+        self.canvas
+            .draw_line((x - CROSSHAIR_SIZE, y), (x - CROSSHAIR_SIZE / 3, y))?;
+        self.canvas
+            .draw_line((x + CROSSHAIR_SIZE / 3, y), (x + CROSSHAIR_SIZE, y))?;
+        self.canvas
+            .draw_line((x, y - CROSSHAIR_SIZE), (x, y - CROSSHAIR_SIZE / 3))?;
+        self.canvas
+            .draw_line((x, y + CROSSHAIR_SIZE / 3), (x, y + CROSSHAIR_SIZE))?;
+        Ok(())
+    } // four short lines with a gap in the middle so the target can still be seen
+
+    fn draw_readout(
+        &mut self,
+        font: &sdl2::ttf::Font,
+        text: &str,
+        color: Color,
+        x: i32,
+        y: i32,
+    ) -> Result<(), String> {
+        let width = ui::text_width(font, text, HUD_TEXT_HEIGHT)?;
+        ui::draw_text(
+            self,
+            font,
+            text,
+            colors::BLACK,
+            Rect::new(x + 2, y + 2, width, HUD_TEXT_HEIGHT),
+        )?; // a drop shadow keeps the text readable over bright walls
+        ui::draw_text(
+            self,
+            font,
+            text,
+            color,
+            Rect::new(x, y, width, HUD_TEXT_HEIGHT),
+        )
+    } // draws a line of HUD text with a shadow behind it
+}
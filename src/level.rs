@@ -1,4 +1,7 @@
-pub use crate::{colors, textures, Sector, Surface, Wall};
+pub use crate::{
+    colors, textures, Action, Activation, Entity, EntityKind, Mover, MoverKind, Sector, Surface,
+    Trigger, TriggerEvent, Wall, WallTexture,
+};
 pub use sdl2::pixels::Color;
 pub const NUM_SECTORS: usize = 4;
 pub const NUM_WALLS: usize = 16;
@@ -16,6 +19,11 @@ pub const INIT_SECTORS: [Sector; NUM_SECTORS] = [
         surface: None,
         surface_points: [0; crate::SCREEN_WIDTH],
         surface_texture: Some(textures::BRAT_TEXTURE),
+        light: 255,
+        mover: None,
+        tag: 0,
+        trigger: None,
+        sky: false,
     },
     Sector {
         wall_start: 4,
@@ -28,6 +36,11 @@ pub const INIT_SECTORS: [Sector; NUM_SECTORS] = [
         surface: None,
         surface_points: [0; crate::SCREEN_WIDTH],
         surface_texture: Some(textures::BRAT_TEXTURE),
+        light: 255,
+        mover: None,
+        tag: 0,
+        trigger: None,
+        sky: false,
     },
     Sector {
         wall_start: 12,
@@ -40,6 +53,11 @@ pub const INIT_SECTORS: [Sector; NUM_SECTORS] = [
         surface: None,
         surface_points: [0; crate::SCREEN_WIDTH],
         surface_texture: Some(textures::BRAT_TEXTURE),
+        light: 255,
+        mover: None,
+        tag: 0,
+        trigger: None,
+        sky: false,
     },
     Sector {
         wall_start: 0,
@@ -52,6 +70,11 @@ pub const INIT_SECTORS: [Sector; NUM_SECTORS] = [
         surface: None,
         surface_points: [0; crate::SCREEN_WIDTH],
         surface_texture: Some(textures::BRAT_TEXTURE),
+        light: 255,
+        mover: None,
+        tag: 0,
+        trigger: None,
+        sky: false,
     },
 ];
 
@@ -67,6 +90,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 32.0,
@@ -77,6 +110,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 32.0,
@@ -87,6 +130,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 0.0,
@@ -97,6 +150,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 64.0,
@@ -107,6 +170,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 64.0,
@@ -117,6 +190,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 96.0,
@@ -127,6 +210,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 96.0,
@@ -137,6 +230,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 64.0,
@@ -147,6 +250,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::ORANGE_TILE_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 62.75,
@@ -157,6 +270,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::ORANGE_TILE_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 98.75,
@@ -167,6 +290,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::ORANGE_TILE_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 96.0,
@@ -177,6 +310,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::ORANGE_TILE_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 0.0,
@@ -187,6 +330,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 0.0,
@@ -197,6 +350,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 32.0,
@@ -207,6 +370,16 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
     Wall {
         x1: 32.0,
@@ -217,5 +390,27 @@ pub const INIT_WALLS: [Wall; NUM_WALLS] = [
         texture: Some(textures::BRAT_TEXTURE),
         u: 1.0,
         v: 1.0,
+        tag: 0,
+        trigger: None,
+        masked: false,
+        opacity: 255,
+        blocking: true,
+        upper: WallTexture::NONE,
+        lower: WallTexture::NONE,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        world_aligned: false,
     },
 ];
+
+//ENTITIES:
+pub fn init_entities() -> Vec<Entity> {
+    vec![Entity {
+        kind: EntityKind::PlayerStart,
+        x: 32.0,
+        y: 32.0,
+        z: 10.0,
+        angle: 0,
+        properties: vec![],
+    }]
+}
@@ -1,4 +1,4 @@
-use crate::level::{INIT_SECTORS, INIT_WALLS, NUM_SECTORS, NUM_WALLS};
+use crate::level::{init_entities, INIT_SECTORS, INIT_WALLS, NUM_SECTORS, NUM_WALLS};
 pub use sdl2::{
     event::Event,
     keyboard::{Keycode, Mod},
@@ -11,7 +11,7 @@ pub use sdl2::{
     EventPump,
 };
 pub use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fs::{File, OpenOptions},
     io::{Read, Write},
     path::{Path, PathBuf},
@@ -19,12 +19,20 @@ pub use std::{
     time::Duration,
     vec::Vec,
 };
+pub mod actors;
+pub mod audio;
+pub mod automap;
 pub mod colors;
+pub mod console;
 pub mod grid;
+pub mod hud;
 pub mod level;
+pub mod mipmaps;
 pub mod renderer;
 #[allow(non_snake_case)]
 pub mod textures;
+pub mod ui;
+pub mod weapons;
 
 //Constants:
 pub const RESOLUTION: usize = 7;
@@ -33,6 +41,12 @@ pub const HALF_WIDTH: usize = SCREEN_WIDTH / 2;
 pub const SCREEN_HEIGHT: usize = RESOLUTION * 120;
 pub const HALF_HEIGHT: usize = SCREEN_HEIGHT / 2;
 pub const PIXEL_SCALE: usize = 1;
+pub const MIN_SCALE: f32 = 0.25; // the furthest the 2D editor can zoom out
+pub const MAX_SCALE: f32 = 64.0; // the furthest the 2D editor can zoom in
+pub const EYE_HEIGHT: i32 = 10; // how far above a sector the player can be and still be standing on it
+pub const USE_RANGE: f32 = 16.0; // how far away a wall can be used from
+pub const PLAYER_HEALTH: i32 = 100; // how much health the player starts with
+pub const WORLD_TEXTURE_SIZE: f32 = 64.0; // how many units of the level one repeat of a world-aligned texture covers
 
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct XYZ {
@@ -43,32 +57,52 @@ pub struct XYZ {
 
 #[derive(Clone, Default, Debug)]
 pub struct PlayerInfo {
-    pub position: XYZ, // the players position in space
-    pub angle_h: i32,  // the horizontal angle of the players field of view
-    pub level: Level,  // the map that the player is currently within; made up of sectors
+    pub position: XYZ,             // the players position in space
+    pub angle_h: i32,              // the horizontal angle of the players field of view
+    pub level: Level, // the map that the player is currently within; made up of sectors
+    pub weapons: weapons::Weapons, // what the player is holding and the shots they've fired
+    pub health: i32,  // the player goes back to the start when this runs out
+    pub noclip: bool, // lets the player walk through sectors
 }
 
 impl PlayerInfo {
     pub fn new() -> PlayerInfo {
-        let init_sectors: Vec<Sector> = Vec::from(INIT_SECTORS); // sectors & their walls are stored in level.rs to allow for editing by draw2d
-        let init_walls: Vec<Wall> = Vec::from(INIT_WALLS);
-        let init_level = Level {
-            number_of_sectors: NUM_SECTORS as u32,
-            sectors: init_sectors,
-            number_of_walls: NUM_WALLS as u32,
-            walls: init_walls,
-        };
-        PlayerInfo {
+        let mut player = PlayerInfo {
             position: XYZ {
                 x: 32,
                 y: 32,
                 z: 10,
             },
             angle_h: 0,
-            level: init_level,
-        }
+            level: Level::load(),
+            weapons: weapons::Weapons::new(),
+            health: PLAYER_HEALTH,
+            noclip: false,
+        };
+        player.start();
+        player
     }
 
+    pub fn start(&mut self) {
+        let start = self
+            .level
+            .entities
+            .iter()
+            .find(|entity| entity.kind == EntityKind::PlayerStart)
+            .cloned();
+        match start {
+            Some(start) => {
+                self.position = XYZ {
+                    x: start.x as i32,
+                    y: start.y as i32,
+                    z: start.z as i32,
+                };
+                self.angle_h = start.angle;
+            }
+            _ => {}
+        } // levels without a player start keep wherever the player already is
+    } // puts the player at the level's player start
+
     pub fn distances(player: &mut PlayerInfo) -> &mut PlayerInfo {
         for sector in player.level.sectors.iter_mut() {
             for (i, wall) in player.level.walls.iter().enumerate() {
@@ -100,9 +134,8 @@ impl PlayerInfo {
             }
             sector.distance /= sector.wall_end as f32 - sector.wall_start as f32;
         }
-        player.level.sectors = sort(player.level.sectors.clone());
         player
-    } // calculates the distance from the player to a sector and sorts the sectors by distance to the player
+    } // calculates the distance from the player to each sector; see draw_order for the order they are drawn in
 
     // player movement funtcions:
     pub fn move_up(player: &mut PlayerInfo) {
@@ -120,35 +153,604 @@ impl PlayerInfo {
     pub fn move_fowward(player: &mut PlayerInfo) {
         let dx = (sine(player.angle_h) * 10.0) as i32;
         let dy = (cosine(player.angle_h) * 10.0) as i32;
-        player.position.x += dx;
-        player.position.y += dy;
+        player.walk(dx, dy);
     }
     pub fn move_right(player: &mut PlayerInfo) {
         let dx = (sine(player.angle_h) * 10.0) as i32;
         let dy = (cosine(player.angle_h) * 10.0) as i32;
-        player.position.x += dy;
-        player.position.y -= dx;
+        player.walk(dy, -dx);
     }
     pub fn move_left(player: &mut PlayerInfo) {
         let dx = (sine(player.angle_h) * 10.0) as i32;
         let dy = (cosine(player.angle_h) * 10.0) as i32;
-        player.position.x -= dy;
-        player.position.y += dx;
+        player.walk(-dy, dx);
     }
     pub fn move_backward(player: &mut PlayerInfo) {
         let dx = (sine(player.angle_h) * 10.0) as i32;
         let dy = (cosine(player.angle_h) * 10.0) as i32;
-        player.position.x -= dx;
-        player.position.y -= dy;
+        player.walk(-dx, -dy);
     }
+
+    pub fn walk(&mut self, dx: i32, dy: i32) {
+        let before = self.standing_in();
+        let (x, y) = (self.position.x as f32, self.position.y as f32);
+        if !self.blocked(self.position.x + dx, self.position.y) {
+            self.position.x += dx;
+        }
+        if !self.blocked(self.position.x, self.position.y + dy) {
+            self.position.y += dy;
+        } // moving along each axis separately lets the player slide along walls
+        if (self.position.x as f32, self.position.y as f32) != (x, y) {
+            self.weapons.bob += 1;
+            if self.weapons.bob.is_multiple_of(audio::FOOTSTEP_TICKS) {
+                self.level.sound(
+                    audio::FOOTSTEP,
+                    self.position.x as f32,
+                    self.position.y as f32,
+                );
+            }
+        } // the weapon only sways while the player is actually moving
+        let after = self.standing_in();
+        match after {
+            Some(s) if after != before => {
+                self.level.activate(s, Activation::Enter);
+                self.trigger_sector(s, TriggerEvent::Enter);
+            }
+            _ => {}
+        }
+        let (new_x, new_y) = (self.position.x as f32, self.position.y as f32);
+        for w in 0..self.level.walls.len() {
+            let wall = self.level.walls[w];
+            match wall.crossed_by(x, y, new_x, new_y) {
+                Some(_) => self.trigger_wall(w, TriggerEvent::Cross),
+                _ => {}
+            }
+        }
+    } // moves the player unless a sector is in the way, setting off any sector they step onto
+
+    pub fn blocked(&self, x: i32, y: i32) -> bool {
+        match self.level.sector_at(x as f32, y as f32) {
+            Some(s) => {
+                !self.noclip && self.level.sectors[s].blocks(self.position.z) && self.level.solid(s)
+            }
+            _ => false,
+        }
+    } // whether the player would be inside of a sector at this position
+
+    pub fn standing_in(&self) -> Option<usize> {
+        self.level
+            .sector_at(self.position.x as f32, self.position.y as f32)
+    } // the sector the player is above or below, if any
+
+    pub fn use_wall(&mut self) {
+        let (x1, y1) = (self.position.x as f32, self.position.y as f32);
+        let x2 = x1 + sine(self.angle_h) * USE_RANGE;
+        let y2 = y1 + cosine(self.angle_h) * USE_RANGE;
+        let mut closest: Option<(usize, f32)> = None;
+        for (w, wall) in self.level.walls.iter().enumerate() {
+            match wall.crossed_by(x1, y1, x2, y2) {
+                Some(t) if closest.map_or(true, |(_, closest_t)| t < closest_t) => {
+                    closest = Some((w, t))
+                }
+                _ => {}
+            }
+        }
+        match closest {
+            Some((w, _)) => {
+                self.trigger_wall(w, TriggerEvent::Use);
+                match self.level.sector_of_wall(w) {
+                    Some(s) => {
+                        self.level.activate(s, Activation::Use);
+                        self.trigger_sector(s, TriggerEvent::Use);
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    } // sets off the sector whose wall the player is facing, if it is close enough
+
+    pub fn trigger_wall(&mut self, w: usize, event: TriggerEvent) {
+        match self.level.walls[w].trigger {
+            Some(trigger) if trigger.event == event => {
+                if trigger.once {
+                    self.level.walls[w].trigger = None;
+                }
+                self.run(trigger);
+            }
+            _ => {}
+        }
+    } // sets off a wall's trigger if it is waiting for this event
+
+    pub fn trigger_sector(&mut self, s: usize, event: TriggerEvent) {
+        match self.level.sectors[s].trigger {
+            Some(trigger) if trigger.event == event => {
+                if trigger.once {
+                    self.level.sectors[s].trigger = None;
+                }
+                self.run(trigger);
+            }
+            _ => {}
+        }
+    } // sets off a sector's trigger if it is waiting for this event
+
+    pub fn run(&mut self, trigger: Trigger) {
+        let tag = trigger.target;
+        match trigger.action {
+            Action::MoveSector { height } => {
+                for s in 0..self.level.sectors.len() {
+                    if self.level.sectors[s].tag == tag {
+                        self.level.move_sector(s, height);
+                    }
+                }
+            }
+            Action::ChangeTexture { texture } => {
+                for wall in self.level.walls.iter_mut().filter(|wall| wall.tag == tag) {
+                    wall.texture = texture;
+                }
+                for sector in self
+                    .level
+                    .sectors
+                    .iter_mut()
+                    .filter(|sector| sector.tag == tag)
+                {
+                    sector.surface_texture = texture;
+                }
+            }
+            Action::Teleport { x, y, z } => self.position = XYZ { x, y, z },
+            Action::EndLevel => self.level.finished = true,
+            Action::PlaySound { sound } => {
+                self.level
+                    .sound(sound, self.position.x as f32, self.position.y as f32)
+            }
+        }
+    } // does what a trigger's action says to every wall & sector with its target tag
+
+    pub fn update_sectors(&mut self) {
+        let standing_in = self.standing_in();
+        for m in (0..self.level.motions.len()).rev() {
+            let mut motion = self.level.motions[m];
+            let mover = self.level.sectors[motion.sector].mover.unwrap_or(Mover {
+                kind: MoverKind::Lift,
+                activation: Activation::Use,
+                bottom: motion.target.0,
+                top: motion.target.1,
+                speed: 1,
+                wait: 0,
+            }); // sectors moved by a trigger move like a lift that doesn't come back
+            let sector = self.level.sectors[motion.sector];
+            let (bottom, top) = (sector.bottom_height, sector.top_height);
+            if (bottom, top) == motion.target {
+                if motion.wait > 0 {
+                    motion.wait -= 1;
+                } else if motion.target == motion.rest && mover.kind != MoverKind::Crusher {
+                    self.level.motions.remove(m);
+                    continue;
+                } else {
+                    motion.target = match motion.target == motion.rest {
+                        true => (mover.bottom, mover.top),
+                        false => motion.rest,
+                    };
+                    motion.wait = mover.wait;
+                } // wait at the end of each move, then go back; crushers never stop
+                self.level.motions[m] = motion;
+                continue;
+            }
+
+            let step = |from: i32, to: i32| {
+                from + mover.speed.max(1).min((to - from).abs()) * (to - from).signum()
+            };
+            let (next_bottom, next_top) =
+                (step(bottom, motion.target.0), step(top, motion.target.1));
+            let on_top = standing_in == Some(motion.sector)
+                && self.position.z <= bottom
+                && bottom - self.position.z <= EYE_HEIGHT;
+            let inside = standing_in == Some(motion.sector)
+                && next_bottom < self.position.z
+                && self.position.z < next_top;
+            if inside && !on_top && mover.kind == MoverKind::Door {
+                motion.target = (mover.bottom, mover.top);
+                motion.wait = mover.wait;
+                self.level.motions[m] = motion;
+                continue;
+            } // doors don't close on the player
+            self.level.sectors[motion.sector].bottom_height = next_bottom;
+            self.level.sectors[motion.sector].top_height = next_top;
+            if on_top || (inside && next_bottom != bottom) {
+                self.position.z += next_bottom - bottom;
+            } else if inside {
+                self.position.z += next_top - top;
+            } // the player rides lifts and is pushed along by crushers coming from either side
+            self.level.motions[m] = motion;
+        }
+    } // moves every moving sector one tick closer to where it is going
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Level {
     pub number_of_sectors: u32,
     pub sectors: Vec<Sector>, // 3d space enclosed by walls on all sides and optionally surfaces on the top and bottom
     pub number_of_walls: u32,
-    pub walls: Vec<Wall>, // horizontal pane used to build sectors
+    pub walls: Vec<Wall>,               // horizontal pane used to build sectors
+    pub entities: Vec<Entity>, // items, enemies, decorations & spawn points placed in the editor
+    pub motions: Vec<Motion>,  // sectors that are currently moving
+    pub sounds: Vec<audio::SoundEvent>, // sounds waiting to be played this tick
+    pub finished: bool,        // set by a trigger when the player reaches the end of the level
+    pub died: bool, // set when the player runs out of health and is sent back to the start
+    pub sprites: Vec<Sprite>, // things in the world that always face the player
+    pub actors: Vec<actors::Actor>, // enemies that are moving around the level
+    pub clock: u32, // ticks the level has been played for; animations run off of this
+    pub neighbours: Vec<Option<usize>>, // the sector on the other side of each wall; rebuilt by link_walls
+}
+
+impl Level {
+    pub fn load() -> Level {
+        let init_sectors: Vec<Sector> = Vec::from(INIT_SECTORS); // sectors & their walls are stored in level.rs to allow for editing by draw2d
+        let init_walls: Vec<Wall> = Vec::from(INIT_WALLS);
+        let mut level = Level {
+            number_of_sectors: NUM_SECTORS as u32,
+            sectors: init_sectors,
+            number_of_walls: NUM_WALLS as u32,
+            walls: init_walls,
+            entities: init_entities(),
+            motions: Vec::new(),
+            sounds: Vec::new(),
+            finished: false,
+            died: false,
+            sprites: Vec::new(),
+            actors: Vec::new(),
+            clock: 0,
+            neighbours: Vec::new(),
+        };
+        level.link_walls();
+        level.spawn();
+        level
+    } // builds the level that was last saved to level.rs
+
+    pub fn activate(&mut self, s: usize, activation: Activation) {
+        let mover = match self.sectors[s].mover {
+            Some(mover) if mover.activation == activation => mover,
+            _ => return,
+        };
+        match self.motions.iter().position(|motion| motion.sector == s) {
+            Some(m) if mover.kind == MoverKind::Crusher => {
+                (self.sectors[s].bottom_height, self.sectors[s].top_height) = self.motions[m].rest;
+                self.motions.remove(m);
+            } // setting off a crusher again stops it
+            Some(_) => {} // already moving
+            _ => {
+                self.motions.push(Motion {
+                    sector: s,
+                    rest: (self.sectors[s].bottom_height, self.sectors[s].top_height),
+                    target: (mover.bottom, mover.top),
+                    wait: mover.wait,
+                });
+                let (x, y) = self.center(s);
+                self.sound(audio::DOOR, x, y);
+            }
+        }
+    } // starts a sector's mover if it was set off the right way
+
+    pub fn move_sector(&mut self, s: usize, height: i32) {
+        self.motions.retain(|motion| motion.sector != s);
+        let target = (height, self.sectors[s].top_height);
+        self.motions.push(Motion {
+            sector: s,
+            rest: target,
+            target,
+            wait: 0,
+        }); // resting where it is going means it won't come back
+        let (x, y) = self.center(s);
+        self.sound(audio::DOOR, x, y);
+    } // starts a sector moving to a new height for good
+
+    pub fn solid(&self, s: usize) -> bool {
+        let sector = self.sectors[s];
+        self.walls[sector.wall_start as usize..sector.wall_end as usize]
+            .iter()
+            .any(|wall| wall.blocking)
+    } // sectors made only of non-blocking walls don't stop the player
+
+    pub fn link_walls(&mut self) {
+        let key = |wall: &Wall| {
+            let (a, b) = (
+                (wall.x1.to_bits(), wall.y1.to_bits()),
+                (wall.x2.to_bits(), wall.y2.to_bits()),
+            );
+            (a.min(b), a.max(b))
+        }; // the same for a wall whichever way round it runs
+        let mut walls_at: HashMap<_, Vec<usize>> = HashMap::new();
+        for (w, wall) in self.walls.iter().enumerate() {
+            walls_at.entry(key(wall)).or_default().push(w);
+        }
+        self.neighbours = (0..self.walls.len())
+            .map(|w| {
+                walls_at[&key(&self.walls[w])]
+                    .iter()
+                    .find(|t| **t != w)
+                    .and_then(|t| self.sector_of_wall(*t))
+            })
+            .collect();
+    } // works out which sector is behind every wall; run whenever the level is loaded or its walls change
+
+    pub fn neighbour_of_wall(&self, w: usize) -> Option<usize> {
+        self.neighbours.get(w).copied().flatten()
+    } // the sector on the other side of a wall, if it is shared
+
+    pub fn align_walls(&mut self, w: usize) {
+        let s = match self.sector_of_wall(w) {
+            Some(s) => s,
+            _ => return,
+        };
+        let sector = self.sectors[s];
+        let (bottom, top) = (sector.bottom_height, sector.top_height);
+        let walls = sector.wall_start as usize..sector.wall_end as usize;
+        let mut previous = sector.wall_start as usize;
+        let mut aligned = vec![previous];
+        let mut end = (self.walls[previous].x2, self.walls[previous].y2); // how far round the loop the walk has got
+        loop {
+            let last = self.walls[previous];
+            let ((start_u, start_v), (span_u, _)) = last.mapping(last.middle(), bottom, top);
+            let u = match end == (last.x2, last.y2) {
+                true => start_u + span_u,
+                false => start_u,
+            }; // how far along the texture is where the last wall meets the next
+            let w = match walls.clone().find(|w| {
+                let wall = self.walls[*w];
+                !aligned.contains(w) && (end == (wall.x1, wall.y1) || end == (wall.x2, wall.y2))
+            }) {
+                Some(w) => w,
+                _ => break, // back to wall_start, or the loop isn't connected past here
+            };
+            let wall = Wall {
+                offset_x: 0.0,
+                offset_y: 0.0,
+                ..self.walls[w]
+            };
+            let ((base_u, base_v), (base_span_u, _)) = wall.mapping(wall.middle(), bottom, top);
+            let (offset_x, far) = match end == (wall.x1, wall.y1) {
+                true => (u - base_u, (wall.x2, wall.y2)),
+                false => (u - base_u - base_span_u, (wall.x1, wall.y1)), // this wall runs back towards the last one
+            };
+            self.walls[w].offset_x = offset_x.rem_euclid(1.0);
+            self.walls[w].offset_y = (start_v - base_v).rem_euclid(1.0);
+            aligned.push(w);
+            (previous, end) = (w, far);
+        }
+    } // walks the sector's loop from wall_start, wall to touching wall, and slides each texture so it carries on from the one before
+
+    pub fn sector_of_wall(&self, w: usize) -> Option<usize> {
+        self.sectors
+            .iter()
+            .position(|sector| sector.wall_start as usize <= w && w < sector.wall_end as usize)
+    } // finds the sector a wall belongs to
+
+    pub fn spawn(&mut self) {
+        self.sprites = Vec::new();
+        self.actors = Vec::new();
+        for (e, entity) in self.entities.iter().enumerate() {
+            match actors::Actor::spawn(e, entity) {
+                Some(actor) => {
+                    self.actors.push(actor);
+                    continue;
+                }
+                _ => {}
+            } // enemies are drawn by their actor instead
+            match entity.texture() {
+                Some(texture) => self.sprites.push(Sprite {
+                    x: entity.x,
+                    y: entity.y,
+                    z: entity.z,
+                    height: entity.height(),
+                    texture,
+                }),
+                _ => {}
+            }
+        }
+    } // creates the things the entities describe so they can be seen in 3D
+
+    pub fn sector_at(&self, x: f32, y: f32) -> Option<usize> {
+        for (s, sector) in self.sectors.iter().enumerate() {
+            let mut inside = false;
+            for wall in &self.walls[sector.wall_start as usize..sector.wall_end as usize] {
+                if (wall.y1 > y) != (wall.y2 > y)
+                    && x < wall.x1 + (y - wall.y1) * (wall.x2 - wall.x1) / (wall.y2 - wall.y1)
+                {
+                    inside = !inside;
+                }
+            } // a point is inside a sector if a line from it crosses the sector's walls an odd number of times
+            if inside {
+                return Some(s);
+            }
+        }
+        None
+    } // finds which sector a point is in, if any
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MoverKind {
+    Door, // opens when set off, waits, then closes again; reopens instead of closing on the player
+    Lift, // carries anyone standing on it to its other height and back
+    Crusher, // keeps moving back & forth until it is set off again
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Activation {
+    Use,   // the player uses one of the sector's walls
+    Enter, // the player steps onto the sector
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mover {
+    pub kind: MoverKind,
+    pub activation: Activation,
+    pub bottom: i32, // the bottom_height the sector moves to when it is set off
+    pub top: i32,    // ... and the top_height, so doors & crushers can close from either end
+    pub speed: i32,  // how far the sector moves each tick
+    pub wait: u32,   // how many ticks the sector waits before moving back
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motion {
+    pub sector: usize,
+    pub rest: (i32, i32), // the bottom_height & top_height the sector started at and returns to
+    pub target: (i32, i32), // the bottom_height & top_height the sector is moving towards
+    pub wait: u32,        // ticks left to wait once the target is reached
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TriggerEvent {
+    Use,   // the player uses the wall, or any wall of the sector
+    Cross, // the player walks across the wall
+    Enter, // the player steps onto the sector
+    Shoot, // the wall or sector is shot
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    MoveSector { height: i32 }, // moves the bottom_height of every tagged sector
+    ChangeTexture { texture: Option<Texture> }, // retextures every tagged wall, or the surface of every tagged sector
+    Teleport { x: i32, y: i32, z: i32 },        // moves the player
+    EndLevel,
+    PlaySound { sound: u32 },
+}
+
+impl Action {
+    pub const NAMES: [&'static str; 5] = [
+        "Move sector",
+        "Change texture",
+        "Teleport",
+        "End level",
+        "Play sound",
+    ];
+
+    pub fn index(&self) -> usize {
+        match self {
+            Action::MoveSector { .. } => 0,
+            Action::ChangeTexture { .. } => 1,
+            Action::Teleport { .. } => 2,
+            Action::EndLevel => 3,
+            Action::PlaySound { .. } => 4,
+        }
+    } // the action's position in NAMES
+
+    pub fn from_index(index: usize) -> Action {
+        match index {
+            0 => Action::MoveSector { height: 0 },
+            1 => Action::ChangeTexture { texture: None },
+            2 => Action::Teleport { x: 0, y: 0, z: 0 },
+            3 => Action::EndLevel,
+            _ => Action::PlaySound { sound: 0 },
+        }
+    } // a new action of the kind at a position in NAMES
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Trigger {
+    pub event: TriggerEvent, // what sets the trigger off
+    pub action: Action,      // what happens when it is set off
+    pub target: u32,         // the tag of the walls & sectors the action is done to
+    pub once: bool,          // whether the trigger is removed after it is set off
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EntityKind {
+    PlayerStart, // where the player is put when the level is loaded
+    Item,
+    Enemy,
+    Decoration,
+}
+
+impl EntityKind {
+    pub const ALL: [EntityKind; 4] = [
+        EntityKind::PlayerStart,
+        EntityKind::Item,
+        EntityKind::Enemy,
+        EntityKind::Decoration,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EntityKind::PlayerStart => "PlayerStart",
+            EntityKind::Item => "Item",
+            EntityKind::Enemy => "Enemy",
+            EntityKind::Decoration => "Decoration",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            EntityKind::PlayerStart => colors::GREEN,
+            EntityKind::Item => colors::YELLOW,
+            EntityKind::Enemy => colors::RED,
+            EntityKind::Decoration => colors::CYAN,
+        }
+    } // the color of the entity's icon in the editor
+
+    pub fn texture(&self) -> Option<Texture> {
+        match self {
+            EntityKind::PlayerStart => None,
+            EntityKind::Item => Some(textures::CONSOLE_1B_TEXTURE),
+            EntityKind::Enemy => Some(textures::BRAT_TEXTURE),
+            EntityKind::Decoration => Some(textures::WOOD_1C_TEXTURE),
+        }
+    } // what the entity looks like in 3D unless it has a texture property
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entity {
+    pub kind: EntityKind,
+    pub x: f32, // where the entity is in the level
+    pub y: f32,
+    pub z: f32,
+    pub angle: i32, // which way the entity faces, like PlayerInfo::angle_h
+    pub properties: Vec<(String, String)>, // anything else about the entity, as keys & values
+}
+
+impl Entity {
+    pub fn new(kind: EntityKind, x: f32, y: f32) -> Entity {
+        Entity {
+            kind,
+            x,
+            y,
+            z: 0.0,
+            angle: 0,
+            properties: Vec::new(),
+        }
+    }
+
+    pub fn property(&self, key: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    } // looks up the value of one of the entity's properties
+
+    pub fn texture(&self) -> Option<Texture> {
+        match self.property("texture") {
+            Some(name) => textures::TEXTURES
+                .iter()
+                .find(|texture| texture.name.trim_end_matches("_TEXTURE") == name.to_uppercase())
+                .copied(),
+            _ => self.kind.texture(),
+        }
+    } // the texture property names one of textures::TEXTURES without its _TEXTURE suffix
+
+    pub fn height(&self) -> f32 {
+        match self.property("height").map(|height| height.parse::<f32>()) {
+            Some(Ok(height)) => height,
+            _ => 20.0,
+        }
+    } // how tall the entity's sprite is
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sprite {
+    pub x: f32, // where the sprite stands
+    pub y: f32,
+    pub z: f32,      // the edge the sprite grows from, like a wall's bottom_height
+    pub height: f32, // its width follows from the shape of its texture
+    pub texture: Texture,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -161,9 +763,108 @@ pub struct Wall {
     pub texture: Option<Texture>,
     pub u: f32,
     pub v: f32,
+    pub tag: u32, // lets triggers find this wall; 0 means it has no tag
+    pub trigger: Option<Trigger>,
+    pub masked: bool, // texels with no alpha are left out so whatever is behind shows through
+    pub opacity: u8,  // 255 is solid, anything less is see-through like glass
+    pub blocking: bool, // a sector whose walls all don't block can be walked through
+    pub upper: WallTexture, // the part above a shorter neighbouring sector
+    pub lower: WallTexture, // the part below a neighbouring sector that starts higher
+    pub offset_x: f32, // slides the texture along the wall, where 1.0 is one repeat
+    pub offset_y: f32, // ... and up it
+    pub world_aligned: bool, // lays the texture out by where the wall is in the level instead of from its first point
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WallTexture {
+    pub texture: Option<Texture>, // None uses the wall's own texture
+    pub u: f32,
+    pub v: f32,
+}
+
+impl WallTexture {
+    pub const NONE: WallTexture = WallTexture {
+        texture: None,
+        u: 1.0,
+        v: 1.0,
+    };
 }
 
 impl Wall {
+    pub fn middle(&self) -> WallTexture {
+        WallTexture {
+            texture: self.texture,
+            u: self.u,
+            v: self.v,
+        }
+    } // the wall's own texture, used wherever there is no neighbouring sector
+
+    pub fn slot(&self, height: f32, neighbour: Option<(i32, i32)>) -> Option<WallTexture> {
+        let slot = match neighbour {
+            Some((bottom, _)) if height < bottom as f32 => self.lower,
+            Some((_, top)) if height > top as f32 => self.upper,
+            Some(_) => return None, // hidden inside of the neighbouring sector
+            _ => return Some(self.middle()),
+        };
+        match slot.texture {
+            Some(_) => Some(slot),
+            _ => Some(WallTexture {
+                texture: self.texture,
+                ..slot
+            }),
+        }
+    } // which texture covers a height on the wall, given the heights of the sector on its other side
+
+    pub fn mapping(&self, slot: WallTexture, bottom: i32, top: i32) -> ((f32, f32), (f32, f32)) {
+        match self.world_aligned {
+            true => {
+                let (start, end) = match (self.x2 - self.x1).abs() >= (self.y2 - self.y1).abs() {
+                    true => (self.x1, self.x2),
+                    false => (self.y1, self.y2),
+                }; // laid along whichever way the wall mostly runs, so walls in a line share a texture
+                (
+                    (
+                        start / WORLD_TEXTURE_SIZE * slot.u + self.offset_x,
+                        bottom as f32 / WORLD_TEXTURE_SIZE * slot.v + self.offset_y,
+                    ),
+                    (
+                        (end - start) / WORLD_TEXTURE_SIZE * slot.u,
+                        (top - bottom) as f32 / WORLD_TEXTURE_SIZE * slot.v,
+                    ),
+                )
+            }
+            false => ((self.offset_x, self.offset_y), (slot.u, slot.v)),
+        }
+    } // where a texture starts at the wall's first point & bottom, and how far it runs across & up to the other end
+
+    pub fn crossed_by(&self, x1: f32, y1: f32, x2: f32, y2: f32) -> Option<f32> {
+        let (x3, y3, x4, y4) = (self.x1, self.y1, self.x2, self.y2);
+        let denominator = (x2 - x1) * (y4 - y3) - (y2 - y1) * (x4 - x3);
+        if denominator == 0.0 {
+            return None;
+        } // parallel lines never cross
+        let t = ((x3 - x1) * (y4 - y3) - (y3 - y1) * (x4 - x3)) / denominator;
+        let u = ((x3 - x1) * (y2 - y1) - (y3 - y1) * (x2 - x1)) / denominator;
+        match (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            true => Some(t),
+            false => None,
+        }
+    } // where along a line (0.0 to 1.0) it crosses the wall, if it does
+
+    pub fn see_through(&self) -> bool {
+        self.masked || self.opacity < 255
+    } // see-through walls are drawn after everything solid
+
+    pub fn contrast(&self) -> i32 {
+        if self.y1 == self.y2 {
+            renderer::WALL_CONTRAST
+        } else if self.x1 == self.x2 {
+            -renderer::WALL_CONTRAST
+        } else {
+            0
+        }
+    } // walls running along x are lit a little brighter than walls running along y so corners stand out
+
     pub fn get_points(self) -> Vec<(f32, f32)> {
         let mut points = Vec::new();
         let x_float = self.x2 - self.x1;
@@ -180,6 +881,10 @@ impl Wall {
         points
     } // returns either the first or second point of a given wall
     pub fn next_texture(&mut self) {
+        if self.texture.is_none() {
+            self.texture = Some(textures::TEXTURES[0]);
+            return;
+        } // a wall without a texture starts at the first one
         for (i, texture) in textures::TEXTURES.iter().enumerate() {
             match self.texture {
                 Some(self_texture) => {
@@ -198,6 +903,10 @@ impl Wall {
         }
     }
     pub fn prev_texture(&mut self) {
+        if self.texture.is_none() {
+            self.texture = Some(textures::TEXTURES[textures::TEXTURES.len() - 1]);
+            return;
+        } // ...  or the last one when going backwards
         for (i, texture) in textures::TEXTURES.iter().enumerate() {
             match self.texture {
                 Some(self_texture) => {
@@ -217,7 +926,7 @@ impl Wall {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Sector {
     pub wall_start: i32, // walls are assigned to sectors ordinally so each sector says which wall indicates its start
     pub wall_end: i32,   // ...  and which wall indicateds its end
@@ -229,6 +938,17 @@ pub struct Sector {
     pub surface_points: [u32; SCREEN_WIDTH], // used to store the value of the points in the visible surface of a sector which are then used to draw the surface on the next loop
     pub surface: Option<Surface>, // indicates which surface (if any) is currently being drawn
     pub surface_texture: Option<Texture>, // texture of the surface
+    pub light: u8,                // how bright the sector is; 255 is full brightness
+    pub mover: Option<Mover>,     // makes the sector a door, lift or crusher
+    pub tag: u32,                 // lets triggers find this sector; 0 means it has no tag
+    pub trigger: Option<Trigger>,
+    pub sky: bool, // the ceiling is open to the sky instead of being drawn as a surface
+}
+
+impl Sector {
+    pub fn blocks(&self, z: i32) -> bool {
+        self.bottom_height < z && z < self.top_height
+    } // whether something at this height can't move through the sector
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -260,25 +980,17 @@ pub fn no_less_than_one(n: i32) -> i32 {
     } else {
         return n;
     }
-} // returns four if the given value is less than four (used to cap grid scale)
+} // returns one if the given value is less than one (used to cap grid size)
 
 pub fn distance(x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
     ((x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1)).sqrt()
 } // calculates simple 2D cartesean distance
 
-pub fn sort(mut sec_vec: Vec<Sector>) -> Vec<Sector> {
-    let mut swapped = true;
-    while swapped {
-        swapped = false;
-        for i in 0..sec_vec.len() - 1 {
-            if sec_vec[i].distance <= sec_vec[i + 1].distance {
-                sec_vec.swap(i, i + 1);
-                swapped = true;
-            }
-        }
-    }
-    return sec_vec;
-} // simple bubble sort for sectors based on distance
+pub fn draw_order(sectors: &[Sector]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..sectors.len()).collect();
+    order.sort_by(|a, b| sectors[*b].distance.total_cmp(&sectors[*a].distance));
+    order
+} // the sectors furthest from the player first, so nearer ones are drawn over them
 
 pub fn mouse_point(mouse_x: f32, mouse_y: f32) -> (f32, f32) {
     (mouse_x, mouse_y)
@@ -291,18 +1003,14 @@ pub fn wall_point(
     point: usize,
 ) -> Result<(f32, f32), String> {
     if point == 1 {
-        Ok((
-            (player.level.walls[wall_number as usize].x1 + grid.view_shift_x as f32)
-                * grid.scale as f32,
-            (player.level.walls[wall_number as usize].y1 + grid.view_shift_y as f32)
-                * grid.scale as f32,
+        Ok(grid.screen_point(
+            player.level.walls[wall_number as usize].x1,
+            player.level.walls[wall_number as usize].y1,
         ))
     } else if point == 2 {
-        Ok((
-            (player.level.walls[wall_number as usize].x2 + grid.view_shift_x as f32)
-                * grid.scale as f32,
-            (player.level.walls[wall_number as usize].y2 + grid.view_shift_y as f32)
-                * grid.scale as f32,
+        Ok(grid.screen_point(
+            player.level.walls[wall_number as usize].x2,
+            player.level.walls[wall_number as usize].y2,
         ))
     } else {
         Err("Error!".to_string())
@@ -323,4 +1031,151 @@ pub struct Texture {
     width: u32,
     height: u32,
     data: &'static [u32],
+    mips: &'static [u32], // the copies half the size, a quarter the size & so on, one after another; see mipmaps::chain
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation {
+    pub texture: Texture, // the texture placed in the editor that this animates
+    pub frames: &'static [Texture], // shown one after another; empty keeps the texture as it is
+    pub rate: u32,        // ticks each frame is shown for
+    pub scroll_u: f32,    // how far the texture slides across every tick; 1.0 is its whole width
+    pub scroll_v: f32,    // ...  and up, where 1.0 is its whole height
+}
+
+impl Animation {
+    pub fn at(&self, clock: u32) -> (Texture, f32, f32) {
+        let texture = match self.frames.len() {
+            0 => self.texture,
+            frames => self.frames[(clock / self.rate.max(1)) as usize % frames],
+        };
+        (
+            texture,
+            (self.scroll_u * clock as f32).fract(),
+            (self.scroll_v * clock as f32).fract(),
+        )
+    } // which frame to show & how far it has scrolled at a moment in the simulation
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub fn wall(x1: f32, y1: f32, x2: f32, y2: f32) -> Wall {
+        Wall {
+            x1,
+            y1,
+            x2,
+            y2,
+            color: colors::WHITE,
+            texture: None,
+            u: 1.0,
+            v: 1.0,
+            tag: 0,
+            trigger: None,
+            masked: false,
+            opacity: 255,
+            blocking: true,
+            upper: WallTexture::NONE,
+            lower: WallTexture::NONE,
+            offset_x: 0.0,
+            offset_y: 0.0,
+            world_aligned: false,
+        }
+    }
+
+    pub fn square(x: f32, y: f32, size: f32) -> Vec<Wall> {
+        vec![
+            wall(x, y, x + size, y),
+            wall(x + size, y, x + size, y + size),
+            wall(x + size, y + size, x, y + size),
+            wall(x, y + size, x, y),
+        ]
+    } // four walls joined end to end, like the editor makes them
+
+    pub fn level(sectors: &[(Vec<Wall>, i32, i32)]) -> Level {
+        let mut level = Level::default();
+        for (walls, bottom_height, top_height) in sectors {
+            level.sectors.push(Sector {
+                wall_start: level.walls.len() as i32,
+                wall_end: (level.walls.len() + walls.len()) as i32,
+                bottom_height: *bottom_height,
+                top_height: *top_height,
+                distance: 0.0,
+                top_color: colors::WHITE,
+                bottom_color: colors::WHITE,
+                surface_points: [0; SCREEN_WIDTH],
+                surface: None,
+                surface_texture: None,
+                light: 255,
+                mover: None,
+                tag: 0,
+                trigger: None,
+                sky: false,
+            });
+            level.walls.extend(walls.iter().copied());
+        }
+        level.number_of_sectors = level.sectors.len() as u32;
+        level.number_of_walls = level.walls.len() as u32;
+        level.link_walls();
+        level
+    } // a level made of sectors given as their walls, bottom_height & top_height
+
+    fn offsets(level: &Level) -> Vec<f32> {
+        level.walls.iter().map(|wall| wall.offset_x).collect()
+    }
+
+    #[test]
+    fn align_walls_carries_the_texture_round_a_square() {
+        let mut level = level(&[(square(0.0, 0.0, 10.0), 0, 100)]);
+        for wall in level.walls.iter_mut() {
+            wall.u = 0.25;
+        }
+        level.align_walls(2);
+        assert_eq!(offsets(&level), vec![0.0, 0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn align_walls_follows_shared_points_not_the_order_of_the_walls() {
+        let walls = square(0.0, 0.0, 10.0);
+        let mut level = level(&[(
+            vec![
+                walls[0],
+                walls[2],
+                wall(10.0, 10.0, 10.0, 0.0), // the right side, drawn the other way
+                walls[3],
+            ],
+            0,
+            100,
+        )]);
+        for wall in level.walls.iter_mut() {
+            wall.u = 0.25;
+        }
+        level.align_walls(0);
+        assert_eq!(offsets(&level), vec![0.0, 0.0, 0.0, 0.25]); // the reversed wall ends at 0.25 where the bottom does
+
+        level.walls[3] = wall(0.0, 5.0, 0.0, 0.0); // a gap in the loop
+        level.walls[3].offset_x = 0.5;
+        level.align_walls(0);
+        assert_eq!(level.walls[3].offset_x, 0.5); // past the gap is left alone
+    }
+
+    #[test]
+    fn shared_walls_link_the_sectors_either_side() {
+        let mut level = level(&[
+            (square(0.0, 0.0, 10.0), 0, 100),
+            (square(10.0, 0.0, 10.0), 0, 100),
+        ]);
+        assert_eq!(level.neighbour_of_wall(1), Some(1)); // x = 10 on the left square's side
+        assert_eq!(level.neighbour_of_wall(7), Some(0)); // ... and on the right square's
+        assert_eq!(level.neighbours.iter().flatten().count(), 2);
+
+        level.walls[7] = wall(10.0, 0.0, 10.0, 10.0); // the same wall drawn the same way round
+        level.link_walls();
+        assert_eq!(level.neighbour_of_wall(1), Some(1));
+
+        level.walls[7] = wall(9.0, 0.0, 9.0, 10.0);
+        level.link_walls();
+        assert_eq!(level.neighbour_of_wall(1), None);
+    }
 }
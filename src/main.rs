@@ -1,6 +1,6 @@
 use timaeus::grid::*;
 
-use timaeus::renderer::{DrawMode::*, Renderer};
+use timaeus::renderer::{editor_area, DrawMode::*, Pick, Renderer};
 
 // git commit ./
 // git push origin main
@@ -28,6 +28,16 @@ fn main() -> Result<(), String> {
         .map_err(|e| e.to_string())?;
 
     let mut renderer = Renderer::new(window)?;
+    video_subsystem.text_input().start(); // lets the editor's number fields be typed into
+    let mut audio = audio::Audio::new(&sdl_context);
+    audio.play_music();
+    let mut console = console::Console::new();
+    match console.load_config(console::CONFIG_PATH) {
+        Ok(()) => {}
+        Err(e) => console.print(&format!("no config loaded: {}", e)),
+    } // the first run has no config yet so the defaults are used
+    console.apply(&mut renderer);
+    let mut last_mode = renderer.draw_mode;
 
     'running: loop {
         if renderer.draw_mode == Draw3D {
@@ -40,9 +50,12 @@ fn main() -> Result<(), String> {
 
         let state = event_pump.mouse_state(); // offset mouse position so that it reflects its position within the actual grid
         let relative_state = event_pump.relative_mouse_state();
-        let screen_x = ((state.x()) as f32 / (grid.scale as f32)) - grid.view_shift_x as f32;
-        let screen_y = ((state.y()) as f32 / (grid.scale as f32)) - grid.view_shift_y as f32;
+        let (screen_x, screen_y) = grid.world_point(state.x(), state.y());
         grid.get_mouse_status(state);
+        grid.snapping = !sdl_context
+            .keyboard()
+            .mod_state()
+            .intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD); // holding shift turns snapping off
 
         for event in event_pump.poll_iter() {
             match event {
@@ -52,125 +65,120 @@ fn main() -> Result<(), String> {
                 } => {
                     match mouse_btn {
                         MouseButton::Left => {
-                            if renderer.draw_mode == renderer::DrawMode::Draw2D {
-                                grid.mouse_status.relative_x = Some(relative_state.x());
-                                grid.mouse_status.relative_y = Some(relative_state.y());
-                                if grid.selected_point.is_none() && grid.state == State::Free {
-                                    //grid.selection.sectors = Vec::new();
-                                    grid.selection.walls = Vec::new();
-                                    grid.selection.points = Vec::new();
-                                    grid.highlight_x = Some(x);
-                                    grid.highlight_y = Some(y)
-                                }
-
-                                if grid.selected_sector.is_some() {
-                                    if grid.selected_wall.is_some() {
-                                        // next texture with left mouse by clicking on preview with left mouse
-                                        if state.y() >= 735 && state.y() <= 825 {
-                                            if state.x() >= 445 && state.x() <= 535 {
-                                                Wall::next_texture(
-                                                    &mut player.level.walls
-                                                        [grid.selected_wall.unwrap()],
-                                                )
-                                            }
-                                        }
-                                    }
-                                    // floor height plus and minus 1 with left mouse
-                                    if state.y() >= 770 && state.y() <= 795 {
-                                        if state.x() >= 150 && state.x() <= 175 {
-                                            player.level.sectors[grid.selected_sector.unwrap()]
-                                                .bottom_height += 1;
-                                        }
-                                        if state.x() >= 190 && state.x() <= 215 {
-                                            player.level.sectors[grid.selected_sector.unwrap()]
-                                                .bottom_height -= 1;
-                                        }
-                                    }
-                                    // ceiling  height plus and minus 1 with left mouse
-                                    if state.y() >= 790 && state.y() <= 815 {
-                                        if state.x() >= 160 && state.x() <= 185 {
-                                            player.level.sectors[grid.selected_sector.unwrap()]
-                                                .top_height += 1;
-                                        }
-                                        if state.x() >= 190 && state.x() <= 215 {
-                                            player.level.sectors[grid.selected_sector.unwrap()]
-                                                .top_height -= 1;
-                                        }
-                                    }
-                                    // wall u plus and minus 1 with left mouse
-                                    if state.y() >= 770 && state.y() <= 795 {
-                                        if state.x() >= 370 && state.x() <= 395 {
-                                            player.level.walls[grid.selected_wall.unwrap()].u +=
-                                                1.0;
-                                        }
-                                        if state.x() >= 400 && state.x() <= 425 {
-                                            player.level.walls[grid.selected_wall.unwrap()].u -=
-                                                1.0;
+                            if renderer.draw_mode != Draw3D {
+                                if grid.ui.is_over(x, y) {
+                                    grid.state = State::Busy; // clicks on the toolbar are handled by its widgets
+                                    grid.ui.click = Some((Button::Left, x, y));
+                                } else {
+                                    grid.ui.focus = None;
+                                    grid.mouse_status.relative_x = Some(relative_state.x());
+                                    grid.mouse_status.relative_y = Some(relative_state.y());
+                                    grid.selected_entity = grid.entity_at(&player, x, y);
+                                    match grid.selected_entity {
+                                        Some(e) => {
+                                            // clicking on an entity selects it and drags it
+                                            let (mouse_x, mouse_y) = grid.world_point(x, y);
+                                            grid.state = State::Busy;
+                                            grid.entity_drag = Some((
+                                                player.level.entities[e].x - mouse_x,
+                                                player.level.entities[e].y - mouse_y,
+                                            ));
                                         }
+                                        _ => {}
                                     }
-                                    //wall v plus and minus 1 with left mouse
-                                    if state.y() >= 790 && state.y() <= 815 {
-                                        if state.x() >= 370 && state.x() <= 395 {
-                                            player.level.walls[grid.selected_wall.unwrap()].v +=
-                                                1.0;
-                                        }
-                                        if state.x() >= 400 && state.x() <= 425 {
-                                            player.level.walls[grid.selected_wall.unwrap()].v -=
-                                                1.0;
+                                    if grid.selected_point.is_none() && grid.state == State::Free {
+                                        match grid.selected_point_at(&player, x, y) {
+                                            Some(point) => {
+                                                // clicking on a selected point drags the whole selection
+                                                let (point_x, point_y) = endpoint(&player, point);
+                                                let (mouse_x, mouse_y) = grid.world_point(x, y);
+                                                grid.state = State::Busy;
+                                                grid.dragging = Some((
+                                                    point,
+                                                    point_x - mouse_x,
+                                                    point_y - mouse_y,
+                                                ));
+                                            }
+                                            _ => {
+                                                grid.selection.clear();
+                                                grid.highlight_x = Some(x);
+                                                grid.highlight_y = Some(y)
+                                            }
                                         }
                                     }
                                 }
                             }
                         }
                         MouseButton::Right => {
-                            if renderer.draw_mode == renderer::DrawMode::Draw2D {
-                                match grid.selected_sector {
-                                    Some(sector) => {
-                                        if grid.selected_wall.is_some() {
-                                            // prev texture with left mouse by clicking on preview with right mouse
-                                            if state.y() >= 735 && state.y() <= 825 {
-                                                if state.x() >= 445 && state.x() <= 535 {
-                                                    Wall::prev_texture(
-                                                        &mut player.level.walls
-                                                            [grid.selected_wall.unwrap()],
-                                                    )
-                                                }
-                                            }
-                                        }
-                                        // floor height plus and minus 10 with right mouse
-                                        if state.y() >= 770 && state.y() <= 795 {
-                                            if state.x() >= 160 && state.x() <= 185 {
-                                                player.level.sectors[sector].bottom_height += 10;
-                                            }
-                                            if state.x() >= 190 && state.x() <= 215 {
-                                                player.level.sectors[sector].bottom_height -= 10;
-                                            }
-                                        }
-                                        // ceiling height plus and minus 10 with right mouse
-                                        if state.y() >= 790 && state.y() <= 815 {
-                                            if state.x() >= 160 && state.x() <= 185 {
-                                                player.level.sectors[sector].top_height += 10;
-                                            }
-                                            if state.x() >= 190 && state.x() <= 215 {
-                                                player.level.sectors[sector].top_height -= 10;
-                                            }
-                                        }
-                                    }
-                                    _ => {} // no selected sector
-                                }
+                            if renderer.draw_mode != Draw3D && grid.ui.is_over(x, y) {
+                                grid.ui.click = Some((Button::Right, x, y));
+                            } else if renderer.draw_mode == Split {
+                                grid.place_camera(&mut player, x, y); // drag to turn it
                             }
                         }
                         _ => {} // no mouse button
                     }
                 }
+                Event::MouseWheel { y, direction, .. } => {
+                    if renderer.draw_mode != Draw3D {
+                        let notches = match direction {
+                            MouseWheelDirection::Flipped => -y,
+                            _ => y,
+                        };
+                        match grid.ui.is_over(state.x(), state.y()) {
+                            true => grid.ui.scroll += notches, // panels like the texture browser scroll instead of zooming
+                            false => grid.zoom(1.25_f32.powi(notches), state.x(), state.y()),
+                        } // zoom towards the cursor
+                    }
+                }
+                Event::MouseMotion {
+                    mousestate,
+                    x,
+                    y,
+                    xrel,
+                    yrel,
+                    ..
+                } => {
+                    if renderer.draw_mode != Draw3D && mousestate.middle() {
+                        grid.pan(xrel, yrel); // drag the view with the middle mouse button
+                    }
+                    if renderer.draw_mode == Split && mousestate.right() && !grid.ui.is_over(x, y) {
+                        grid.aim_camera(&mut player, x, y); // the preview's camera faces the cursor
+                    }
+                }
                 Event::MouseButtonUp { mouse_btn, .. } => {
                     if mouse_btn == MouseButton::Left {
                         grid.state = State::Free;
                         grid.highlight_x = None;
                         grid.highlight_y = None;
+                        grid.dragging = None;
+                        grid.entity_drag = None;
                     }
                 }
 
+                Event::KeyDown {
+                    keycode: Some(Keycode::Backquote),
+                    ..
+                } => console.open = !console.open,
+                Event::KeyDown {
+                    keycode: Some(keycode),
+                    ..
+                } if console.open => console.key(keycode, &mut player, &mut renderer), // the console gets the keyboard before anything else
+                Event::KeyDown {
+                    keycode: Some(Keycode::Escape),
+                    ..
+                } if grid.ui.focus.is_some() => grid.ui.focus = None, // stops typing or closes a list without deselecting anything
+                Event::KeyDown {
+                    keycode: Some(keycode),
+                    ..
+                } if grid.ui.takes_key(keycode) => grid.ui.keys.push(keycode), // a focused widget gets its keys before the editor does
+                Event::TextInput { text, .. } => {
+                    if console.open {
+                        console.type_text(&text);
+                    } else if grid.ui.focus.is_some() {
+                        grid.ui.typed.push_str(&text);
+                    }
+                }
                 Event::KeyDown {
                     keycode: Some(keycode),
                     keymod: Mod::NOMOD,
@@ -178,28 +186,55 @@ fn main() -> Result<(), String> {
                 } => match keycode {
                     Keycode::Q => break 'running,
                     Keycode::Escape => Grid::deselect(&mut grid),
-                    Keycode::Equals => grid.scale += 1,
-                    Keycode::Minus => grid.scale -= 1,
+                    Keycode::Equals => match renderer.draw_mode {
+                        Draw3D => renderer.automap.zoom(1.25),
+                        _ => {
+                            let area = editor_area(renderer.draw_mode);
+                            grid.zoom(1.25, area.center().x(), area.center().y())
+                        }
+                    },
+                    Keycode::Minus => match renderer.draw_mode {
+                        Draw3D => renderer.automap.zoom(0.8),
+                        _ => {
+                            let area = editor_area(renderer.draw_mode);
+                            grid.zoom(0.8, area.center().x(), area.center().y())
+                        }
+                    },
+                    Keycode::Tab => renderer.automap.open = !renderer.automap.open,
+                    Keycode::R => renderer.automap.rotate = !renderer.automap.rotate,
+                    Keycode::F => grid.frame_level(&player, editor_area(renderer.draw_mode)),
                     Keycode::Up => match renderer.draw_mode {
                         Draw3D => PlayerInfo::move_up(&mut player),
-                        Draw2D => Grid::view_up(&mut grid),
+                        Draw2D | Split => match grid.selection.is_empty() {
+                            true => Grid::view_up(&mut grid),
+                            false => grid.nudge_selection(&mut player, 0.0, -1.0),
+                        },
                     },
                     Keycode::Left => match renderer.draw_mode {
                         Draw3D => PlayerInfo::move_left(&mut player),
-                        Draw2D => Grid::view_left(&mut grid),
+                        Draw2D | Split => match grid.selection.is_empty() {
+                            true => Grid::view_left(&mut grid),
+                            false => grid.nudge_selection(&mut player, -1.0, 0.0),
+                        },
                     },
                     Keycode::Down => match renderer.draw_mode {
                         Draw3D => PlayerInfo::move_down(&mut player),
-                        Draw2D => Grid::view_down(&mut grid),
+                        Draw2D | Split => match grid.selection.is_empty() {
+                            true => Grid::view_down(&mut grid),
+                            false => grid.nudge_selection(&mut player, 0.0, 1.0),
+                        },
                     },
                     Keycode::Right => match renderer.draw_mode {
                         Draw3D => PlayerInfo::move_right(&mut player),
-                        Draw2D => Grid::view_right(&mut grid),
+                        Draw2D | Split => match grid.selection.is_empty() {
+                            true => Grid::view_right(&mut grid),
+                            false => grid.nudge_selection(&mut player, 1.0, 0.0),
+                        },
                     },
 
                     Keycode::W => match renderer.draw_mode {
                         Draw3D => PlayerInfo::move_fowward(&mut player),
-                        Draw2D => Grid::next_wall(&mut grid, &mut player),
+                        Draw2D | Split => Grid::next_wall(&mut grid, &mut player),
                     },
                     Keycode::A => PlayerInfo::look_left(&mut player),
                     Keycode::S => PlayerInfo::move_backward(&mut player),
@@ -207,16 +242,70 @@ fn main() -> Result<(), String> {
                     Keycode::J => save(&mut player),
                     Keycode::M => match renderer.draw_mode {
                         Draw3D => renderer.draw_mode = Draw2D,
-                        Draw2D => renderer.draw_mode = Draw3D,
-                    },
+                        Draw2D => renderer.draw_mode = Split,
+                        Split => renderer.draw_mode = Draw3D,
+                    }, // 3D -> 2D -> 2D with a 3D preview -> 3D
                     Keycode::N => Grid::new_sector(&mut grid, &mut player),
+                    Keycode::T => Grid::toggle_browser(&mut grid),
+                    Keycode::LeftBracket => match renderer.draw_mode {
+                        Draw3D => pick(&renderer, |p| p.pan(&mut player, -0.25, 0.0)),
+                        Draw2D | Split => Grid::grid_smaller(&mut grid),
+                    },
+                    Keycode::RightBracket => match renderer.draw_mode {
+                        Draw3D => pick(&renderer, |p| p.pan(&mut player, 0.25, 0.0)),
+                        Draw2D | Split => Grid::grid_larger(&mut grid),
+                    },
+                    Keycode::Semicolon => pick(&renderer, |p| p.pan(&mut player, 0.0, -0.25)),
+                    Keycode::Quote => pick(&renderer, |p| p.pan(&mut player, 0.0, 0.25)),
+                    Keycode::PageUp => pick(&renderer, |p| p.raise(&mut player, 1)),
+                    Keycode::PageDown => pick(&renderer, |p| p.raise(&mut player, -1)),
+                    Keycode::Period => pick(&renderer, |p| p.cycle_texture(&mut player, true)),
+                    Keycode::Comma => pick(&renderer, |p| p.cycle_texture(&mut player, false)),
+                    Keycode::G => pick(&renderer, |p| p.align(&mut player)),
+                    Keycode::E => renderer.pick_mode = !renderer.pick_mode,
                     Keycode::Y => grid.new_sector = Some(Vec::new()),
-                    Keycode::P => {
-                        println!("{:?}", player.position)
-                    }
-
+                    Keycode::I => match renderer.draw_mode {
+                        Draw2D | Split => grid.add_entity(&mut player, state.x(), state.y()),
+                        Draw3D => {}
+                    },
+                    Keycode::Num1 => player.weapons.switch(0),
+                    Keycode::Num2 => player.weapons.switch(1),
+                    Keycode::H => renderer.hud.show_fps = !renderer.hud.show_fps,
+                    Keycode::Space => match renderer.draw_mode {
+                        Draw3D => player.use_wall(),
+                        _ => {}
+                    },
+                    Keycode::Delete => match renderer.draw_mode {
+                        Draw2D | Split => grid.delete_entity(&mut player),
+                        Draw3D => {}
+                    },
+                    _ => {}
+                },
+                Event::KeyDown {
+                    keycode: Some(keycode),
+                    keymod,
+                    ..
+                } if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) => match keycode {
+                    Keycode::C => grid.copy_selection(&player),
+                    Keycode::V => grid.paste(&mut player, screen_x, screen_y),
                     _ => {}
                 },
+                Event::KeyDown {
+                    keycode: Some(keycode),
+                    keymod,
+                    ..
+                } if keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD)
+                    && renderer.draw_mode != Draw3D
+                    && !grid.selection.is_empty() =>
+                {
+                    match keycode {
+                        Keycode::Up => grid.nudge_selection(&mut player, 0.0, -1.0),
+                        Keycode::Left => grid.nudge_selection(&mut player, -1.0, 0.0),
+                        Keycode::Down => grid.nudge_selection(&mut player, 0.0, 1.0),
+                        Keycode::Right => grid.nudge_selection(&mut player, 1.0, 0.0),
+                        _ => {}
+                    }
+                } // snapping is off while shift is held, so these nudge by one unit
                 _ => {}
             }
         }
@@ -231,28 +320,17 @@ fn main() -> Result<(), String> {
             grid.selection.points = Vec::new();
         } // if a point is selected clear grid.selection as to not highlight any points besides the one being moved
 
+        if grid.highlight_x.is_some() {
+            let init_x = grid.highlight_x.unwrap();
+            let init_y = grid.highlight_y.unwrap();
+            let highlight = renderer.highlight_rectangle(&mut grid, init_x, init_y, false)?;
+            grid.select_rectangle(&player, highlight); // everything inside of the highlighted region is added to the selection
+        }
+
         for sector in 0..player.level.number_of_sectors {
             for wall_number in player.level.sectors[sector as usize].wall_start
                 ..player.level.sectors[sector as usize].wall_end
             {
-                if grid.highlight_x.is_some() {
-                    let init_x = grid.highlight_x.unwrap();
-                    let init_y = grid.highlight_y.unwrap();
-                    let highghlight =
-                        renderer.highlight_rectangle(&mut grid, init_x, init_y, false)?;
-
-                    if highghlight.contains_point((
-                        ((player.level.walls[wall_number as usize].x1 + grid.view_shift_x as f32)
-                            * grid.scale as f32) as i32,
-                        ((player.level.walls[wall_number as usize].y1 + grid.view_shift_y as f32)
-                            * grid.scale as f32) as i32,
-                    )) {
-                        if wall_number > 0 {
-                            let point_number = (2 * wall_number as usize) - 1;
-                            grid.selection.points.push(point_number); // if a point is contained in a highlighted regin it is added to selection.points which
-                        } // is then used to render the little white selection cirlces on all of the points in selection.points
-                    }
-                }
                 if grid.mouse_status.button == Some(Button::Left) && grid.state == State::Free {
                     let wall = wall_number as usize;
                     let mut next_is_last = false;
@@ -354,6 +432,13 @@ fn main() -> Result<(), String> {
                             point -= 4;
                         }
 
+                        let (screen_x, screen_y) = grid.snap(
+                            &player,
+                            screen_x,
+                            screen_y,
+                            &[2 * wall, 2 * wall + 1, 2 * second_wall, 2 * second_wall + 1],
+                        ); // the moved point can't snap to its own walls
+
                         match point {
                             1 => {
                                 player.level.walls[wall].y1 = screen_y;
@@ -381,6 +466,7 @@ fn main() -> Result<(), String> {
                             }
                             _ => {}
                         }
+                        player.level.link_walls(); // the moved point may now join or leave another sector
                     }
                     _ => {}
                 }
@@ -388,6 +474,37 @@ fn main() -> Result<(), String> {
             _ => {}
         }
 
+        grid.drag_selection(&mut player, screen_x, screen_y); // moves the whole selection along with the mouse
+        grid.drag_entity(&mut player, screen_x, screen_y);
+        match renderer.draw_mode {
+            Draw3D => {
+                if last_mode != Draw3D {
+                    player.level.spawn();
+                } // the enemies start over each time play starts
+                if state.left() && !console.open {
+                    player.fire();
+                } // holding the left mouse button keeps firing as fast as the weapon allows
+                player.level.clock += 1; // the simulation clock that animated textures run on
+                player.update_weapons();
+                player.update_sectors();
+                player.update_actors();
+                for sound in std::mem::take(&mut player.level.sounds) {
+                    audio.play(sound, &player);
+                }
+                if player.level.finished {
+                    renderer.hud.show("LEVEL COMPLETE");
+                    player.level.finished = false;
+                    player.start();
+                } // back to the player start
+                if player.level.died {
+                    renderer.hud.show("SOAKED");
+                    player.level.died = false;
+                }
+            }
+            _ => {} // while editing, the preview is respawned by whatever changes an entity
+        } // doors & lifts only move while playing
+        last_mode = renderer.draw_mode;
+
         _frame_count += 1;
         // let player_clone = player.clone();
         // let grid_clone = grid.clone();
@@ -397,7 +514,7 @@ fn main() -> Result<(), String> {
         //     debug2 = Some(debug(player_clone, grid_clone, None));
         // }
 
-        Renderer::draw(&mut renderer, &mut player, &mut grid, &font)?;
+        Renderer::draw(&mut renderer, &mut player, &mut grid, &console, &font)?;
     }
 
     std::thread::sleep(frame_duration);
@@ -432,3 +549,10 @@ pub fn debug(player: PlayerInfo, grid: grid::Grid, prev_frame: Option<Debug>) ->
     }
     Debug { player, grid }
 }
+
+fn pick(renderer: &Renderer, edit: impl FnOnce(Pick)) {
+    match (renderer.draw_mode, renderer.pick_mode, renderer.pick) {
+        (Draw3D, true, Some(pick)) => edit(pick),
+        _ => {}
+    }
+} // edits whatever is under the crosshair, only while picking in the 3D view
@@ -0,0 +1,199 @@
+use crate::*;
+
+pub const MIP_LEVELS: usize = 6; // a 64x64 texture goes all the way down to 2x2
+
+pub const fn chain_length(width: u32, height: u32) -> usize {
+    let (mut width, mut height, mut levels, mut length) = (width, height, 1, 0);
+    while levels < MIP_LEVELS && width >= 2 && height >= 2 {
+        (width, height) = (width / 2, height / 2);
+        length += (width * height) as usize;
+        levels += 1;
+    }
+    length
+} // how many texels the smaller copies of a texture take up altogether
+
+pub const fn chain<const N: usize>(data: &[u32], width: u32) -> [u32; N] {
+    let mut mips = [0; N];
+    let (mut width, mut height) = (width as usize, data.len() / width as usize);
+    let (mut source, mut written, mut first) = (0, 0, true); // where the level being halved starts & where the next one goes
+    while written < N {
+        let (half_width, half_height) = (width / 2, height / 2);
+        let mut row = 0;
+        while row < half_height {
+            let mut column = 0;
+            while column < half_width {
+                let mut texels = [0; 4];
+                let mut t = 0;
+                while t < 4 {
+                    let i = (row * 2 + t / 2) * width + column * 2 + t % 2;
+                    texels[t] = match first {
+                        true => data[i],
+                        false => mips[source + i],
+                    };
+                    t += 1;
+                }
+                mips[written + row * half_width + column] = blend(texels);
+                column += 1;
+            }
+            row += 1;
+        }
+        (source, written, first) = (written, written + half_width * half_height, false);
+        (width, height) = (half_width, half_height);
+    } // each level is made from the one before it
+    mips
+} // every smaller copy of a texture, one after another, built when the game is compiled
+
+pub const fn blend(texels: [u32; 4]) -> u32 {
+    let (mut sum, mut opaque) = ([0u32; 4], 0);
+    let mut t = 0;
+    while t < 4 {
+        let bytes = texels[t].to_le_bytes();
+        if bytes[3] != 0 {
+            let mut channel = 0;
+            while channel < 4 {
+                sum[channel] += bytes[channel] as u32;
+                channel += 1;
+            }
+            opaque += 1;
+        } // see-through texels would darken the edges of holes, so they are left out
+        t += 1;
+    }
+    match opaque {
+        0..=2 => 0, // half or more holes stays a hole, so masked walls don't fill in with distance
+        _ => u32::from_le_bytes([
+            (sum[0] / opaque) as u8,
+            (sum[1] / opaque) as u8,
+            (sum[2] / opaque) as u8,
+            (sum[3] / opaque) as u8,
+        ]),
+    }
+} // the texel that stands in for four in the copy half the size
+
+pub fn level(step: f32) -> usize {
+    match step > 1.0 {
+        true => step.log2() as usize,
+        false => 0,
+    }
+} // which copy has about one texel per pixel, given how many texels a pixel covers
+
+impl Texture {
+    pub fn mip(&self, step: f32) -> Texture {
+        let mut mip = Texture { mips: &[], ..*self };
+        let mut start = 0;
+        for _ in 0..level(step) {
+            let (width, height) = (mip.width / 2, mip.height / 2);
+            let end = start + (width * height) as usize;
+            if width == 0 || height == 0 || end > self.mips.len() {
+                break;
+            } // the smallest copy is used for anything further away
+            mip = Texture {
+                width,
+                height,
+                data: &self.mips[start..end],
+                ..mip
+            };
+            start = end;
+        }
+        mip
+    } // the copy of a texture to draw a wall with; only walls are mipmapped, floors & ceilings use the texture as it is
+}
+
+pub fn sample_bilinear(texture: Texture, u: f32, v: f32) -> Color {
+    let x = u * texture.width as f32 - 0.5;
+    let y = v * texture.height as f32 - 0.5;
+    let (fx, fy) = (x - x.floor(), y - y.floor());
+    let texel = |column: f32, row: f32| {
+        let column = (column as i32).rem_euclid(texture.width as i32) as u32;
+        let row = (row as i32).rem_euclid(texture.height as i32) as u32;
+        texture.data[(row * texture.width + column) as usize].to_le_bytes()
+    };
+    let (a, b) = (
+        texel(x.floor(), y.floor()),
+        texel(x.floor() + 1.0, y.floor()),
+    );
+    let (c, d) = (
+        texel(x.floor(), y.floor() + 1.0),
+        texel(x.floor() + 1.0, y.floor() + 1.0),
+    );
+    let mix = |channel: usize| {
+        let top = a[channel] as f32 * (1.0 - fx) + b[channel] as f32 * fx;
+        let bottom = c[channel] as f32 * (1.0 - fx) + d[channel] as f32 * fx;
+        (top * (1.0 - fy) + bottom * fy) as u8
+    };
+    Color {
+        r: mix(0),
+        g: mix(1),
+        b: mix(2),
+        a: mix(3),
+    }
+} // like sample, but blends the four nearest texels so close-up walls look smooth instead of blocky
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_averages_opaque_texels() {
+        assert_eq!(
+            blend([0xff000000, 0xff000010, 0xff001000, 0xff100000]),
+            0xff040404
+        );
+        assert_eq!(
+            blend([0xff000030, 0xff000030, 0xff000030, 0x000000ff]),
+            0xff000030
+        ); // the hole's color is left out
+    }
+
+    #[test]
+    fn blend_keeps_holes() {
+        assert_eq!(blend([0xffffffff, 0xffffffff, 0, 0]), 0);
+        assert_eq!(blend([0; 4]), 0);
+    }
+
+    #[test]
+    fn chain_halves_down_to_the_smallest_level() {
+        assert_eq!(
+            chain_length(64, 64),
+            32 * 32 + 16 * 16 + 8 * 8 + 4 * 4 + 2 * 2
+        );
+        assert_eq!(chain_length(4, 2), 2);
+        assert_eq!(chain_length(1, 8), 0);
+
+        let data = [
+            0xff000010, 0xff000010, 0, 0, //
+            0xff000010, 0xff000010, 0, 0, //
+            0xff000020, 0xff000020, 0xff000030, 0xff000030, //
+            0xff000020, 0xff000020, 0xff000030, 0xff000030, //
+        ];
+        let mips = chain::<{ chain_length(4, 4) }>(&data, 4);
+        assert_eq!(mips, [0xff000010, 0, 0xff000020, 0xff000030, 0xff000020]);
+    }
+
+    #[test]
+    fn level_is_log2_of_step() {
+        assert_eq!(level(0.5), 0);
+        assert_eq!(level(1.0), 0);
+        assert_eq!(level(1.9), 0);
+        assert_eq!(level(2.0), 1);
+        assert_eq!(level(3.9), 1);
+        assert_eq!(level(4.0), 2);
+        assert_eq!(level(64.0), 6);
+    }
+
+    #[test]
+    fn mip_stops_at_the_smallest_copy() {
+        let texture = textures::BRICK_1A_TEXTURE;
+        assert_eq!(
+            texture.mip(1.0),
+            Texture {
+                mips: &[],
+                ..texture
+            }
+        );
+        assert_eq!((texture.mip(2.0).width, texture.mip(2.0).height), (32, 32));
+        assert_eq!(texture.mip(9.0).width, 8);
+        assert_eq!(texture.mip(1000.0).width, 2);
+        assert_eq!(texture.mip(1000.0).data.len(), 4);
+        assert_eq!(textures::SKY_TEXTURE.mip(8.0).width, 128); // textures without a chain stay full size
+    }
+}
@@ -1,5 +1,3 @@
-use textures::WATER_GUN_TEXTURE;
-
 use crate::grid::Grid;
 use crate::*;
 
@@ -7,11 +5,114 @@ use crate::*;
 pub enum DrawMode {
     Draw2D,
     Draw3D,
+    Split, // the 2D editor with a live 3D preview in the corner
+}
+
+pub const FOV: f32 = 700.0; // how far the projection plane is from the player by default
+pub const LIGHT_FALLOFF: f32 = 0.5; // how much light is lost for every unit away from the player
+pub const LIGHT_BANDS: i32 = 32; // like a colormap, light is rounded down to one of this many levels
+pub const WALL_CONTRAST: i32 = 16; // how much brighter or darker a wall is depending on which way it faces
+pub const SKY_REPEATS: f32 = 4.0; // how many times the sky texture wraps around a full turn
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaskedWall {
+    pub x1: f32, // where the wall was on screen when it was put off
+    pub x2: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub t1: f32,
+    pub t2: f32,
+    pub color: Color,
+    pub sector: Sector,
+    pub wall: Wall,
+    pub neighbour: Option<(i32, i32)>,
+    pub distance: f32, // how far the middle of the wall is from the player
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pick {
+    Wall {
+        sector: usize,
+        wall: usize,
+        height: f32,
+    },
+    Floor {
+        sector: usize,
+    },
+    Ceiling {
+        sector: usize,
+    },
+} // what is under the crosshair in 3D, height is how far up the wall (0.0 bottom, 1.0 top)
+
+impl Pick {
+    pub fn raise(&self, player: &mut PlayerInfo, amount: i32) {
+        match *self {
+            Pick::Floor { sector } => player.level.sectors[sector].bottom_height += amount,
+            Pick::Ceiling { sector } => player.level.sectors[sector].top_height += amount,
+            Pick::Wall { sector, height, .. } => match height < 0.5 {
+                true => player.level.sectors[sector].bottom_height += amount,
+                false => player.level.sectors[sector].top_height += amount,
+            }, // the lower half of a wall moves the floor, the upper half the ceiling
+        }
+    } // moves the floor or ceiling under the crosshair up or down
+
+    pub fn cycle_texture(&self, player: &mut PlayerInfo, forward: bool) {
+        match *self {
+            Pick::Wall { wall, .. } => match forward {
+                true => player.level.walls[wall].next_texture(),
+                false => player.level.walls[wall].prev_texture(),
+            },
+            Pick::Floor { sector } | Pick::Ceiling { sector } => {
+                let sector = &mut player.level.sectors[sector];
+                let count = textures::TEXTURES.len();
+                let index = match grid::texture_index(sector.surface_texture) {
+                    0 => match forward {
+                        true => 0,
+                        false => count - 1,
+                    },
+                    i => match forward {
+                        true => i % count,
+                        false => (i + count - 2) % count,
+                    },
+                }; // the same order as Wall::next_texture, starting from either end when untextured
+                sector.surface_texture = Some(textures::TEXTURES[index]);
+            }
+        }
+    } // steps through the texture list for whatever is under the crosshair
+
+    pub fn pan(&self, player: &mut PlayerInfo, du: f32, dv: f32) {
+        match *self {
+            Pick::Wall { wall, .. } => {
+                let wall = &mut player.level.walls[wall];
+                wall.u = (wall.u + du).max(0.25);
+                wall.v = (wall.v + dv).max(0.25);
+            }
+            _ => {}
+        }
+    } // changes how many times a wall's texture repeats
+
+    pub fn align(&self, player: &mut PlayerInfo) {
+        match *self {
+            Pick::Wall { wall, .. } => player.level.align_walls(wall),
+            _ => {}
+        }
+    } // lines up the textures around the sector of the wall under the crosshair
 }
 
 pub struct Renderer {
     pub canvas: Canvas<Window>,
     pub draw_mode: DrawMode,
+    pub pick_mode: bool,
+    pub pick: Option<Pick>,
+    pub depth_buffer: Vec<f32>, // how far away the wall or surface drawn at each pixel is, used to hide sprites behind them
+    pub hud: hud::Hud,
+    pub focal: f32, // how far the projection plane is from the player; set by the fov cvar
+    pub wireframe: bool, // draws only the outlines of walls in 3D
+    pub automap: automap::Automap,
+    pub masked: Vec<MaskedWall>, // see-through walls waiting to be drawn after everything solid
+    pub masked_pass: bool,       // set while the see-through walls are being drawn
+    pub mipmapping: bool, // draws far walls from smaller copies of their textures so they don't shimmer
+    pub bilinear: bool, // smooths textures on walls close enough that a texel covers several pixels
 }
 
 impl Renderer {
@@ -20,6 +121,17 @@ impl Renderer {
         Ok(Renderer {
             canvas,
             draw_mode: DrawMode::Draw3D,
+            pick_mode: false,
+            pick: None,
+            depth_buffer: vec![f32::INFINITY; SCREEN_WIDTH * SCREEN_HEIGHT],
+            hud: hud::Hud::new(),
+            focal: FOV,
+            wireframe: false,
+            automap: automap::Automap::new(),
+            masked: Vec::new(),
+            masked_pass: false,
+            mipmapping: true,
+            bilinear: false,
         })
     } // Create a new renderer from nuthin!
 
@@ -32,18 +144,117 @@ impl Renderer {
         &mut self,
         player: &mut PlayerInfo,
         grid: &mut Grid,
+        console: &console::Console,
         font: &sdl2::ttf::Font,
     ) -> Result<(), String> {
         self.draw_background();
         match self.draw_mode {
             DrawMode::Draw2D => self.draw2d(player, grid, font)?,
-            DrawMode::Draw3D => self.draw3d(player)?,
+            DrawMode::Split => {
+                self.canvas.set_clip_rect(editor_area(DrawMode::Split));
+                let drawn = self.draw2d(player, grid, font);
+                self.canvas.set_clip_rect(None);
+                drawn?; // the map stays in the left half
+                self.draw_preview(player)?;
+                grid.ui.areas.push(preview_area()); // clicks on the preview don't reach the map
+            }
+            DrawMode::Draw3D => {
+                self.draw3d(player)?;
+                self.draw_first_person(player)?;
+                self.draw_automap(player)?;
+                self.draw_hud(player, font)?;
+                if self.pick_mode {
+                    self.draw_pick(player, font)?;
+                }
+            }
         };
+        self.draw_console(console, font)?;
 
         self.canvas.present();
         Ok(())
     } // Top level draw function that runs every tick
 
+    fn draw_preview(&mut self, player: &mut PlayerInfo) -> Result<(), String> {
+        let area = preview_area();
+        self.canvas.set_draw_color(colors::GREY4);
+        self.canvas.fill_rect(area)?;
+        let scale = area.width() as f32 / SCREEN_WIDTH as f32; // shrunk the same amount both ways so the view isn't stretched
+        let height = (SCREEN_HEIGHT as f32 * scale) as u32;
+        let view = Rect::new(
+            area.x(),
+            area.y() + (area.height() - height) as i32 / 2,
+            area.width(),
+            height,
+        ); // the middle of the right half
+        self.canvas.set_scale(scale, scale)?;
+        self.canvas.set_viewport(Rect::new(
+            (view.x() as f32 / scale) as i32,
+            (view.y() as f32 / scale) as i32,
+            SCREEN_WIDTH as u32,
+            SCREEN_HEIGHT as u32,
+        )); // the viewport is given in scaled coordinates
+        self.canvas.set_draw_color(Color::GRAY);
+        self.canvas
+            .fill_rect(Rect::new(0, 0, SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32))?;
+        let drawn = self.draw3d(player);
+        self.canvas.set_viewport(None);
+        self.canvas.set_scale(1.0, 1.0)?;
+        drawn?;
+
+        self.canvas.set_draw_color(colors::WHITE);
+        self.canvas.draw_rect(view)?;
+        Ok(())
+    } // draws the 3D view shrunk down into the right half of the split view, without the weapon or HUD
+
+    fn draw_pick(&mut self, player: &PlayerInfo, font: &sdl2::ttf::Font) -> Result<(), String> {
+        let (x, y) = (
+            (HALF_WIDTH * PIXEL_SCALE) as i32,
+            (HALF_HEIGHT * PIXEL_SCALE) as i32,
+        );
+        self.canvas.set_draw_color(colors::WHITE);
+        self.canvas.draw_line((x - 8, y), (x + 8, y))?;
+        self.canvas.draw_line((x, y - 8), (x, y + 8))?;
+
+        let info = match self.pick {
+            Some(Pick::Wall { sector, wall, .. }) => {
+                let index = wall;
+                let wall = player.level.walls[wall];
+                format!(
+                    "sector {}  wall {}  texture {}  u {}  v {}",
+                    sector,
+                    index,
+                    grid::texture_names()[grid::texture_index(wall.texture)],
+                    wall.u,
+                    wall.v
+                )
+            }
+            Some(Pick::Floor { sector }) => format!(
+                "floor {}  z {}  texture {}",
+                sector,
+                player.level.sectors[sector].bottom_height,
+                grid::texture_names()
+                    [grid::texture_index(player.level.sectors[sector].surface_texture)]
+            ),
+            Some(Pick::Ceiling { sector }) => format!(
+                "ceiling {}  z {}  texture {}",
+                sector,
+                player.level.sectors[sector].top_height,
+                grid::texture_names()
+                    [grid::texture_index(player.level.sectors[sector].surface_texture)]
+            ),
+            _ => "nothing".to_string(),
+        };
+        let height = 24;
+        let width = ui::text_width(font, &info, height)?;
+        ui::draw_text(
+            self,
+            font,
+            &info,
+            colors::WHITE,
+            Rect::new(8, 8, width, height),
+        )
+    } // draws a crosshair and describes what it is pointing at while picking in 3D
+
     pub fn draw_dot(&mut self, x: f32, y: f32, color: Color) -> Result<(), String> {
         self.canvas.set_draw_color(color);
         self.canvas.fill_rect(Rect::new(
@@ -86,11 +297,13 @@ impl Renderer {
         t1: f32,
         t2: f32,
         cycle: u32,
-        _color: Color,
+        color: Color,
         sector: &mut Sector,
         wall: &mut Wall,
-    ) -> Result<(), String> {
-        //hold difference in distance
+        neighbour: Option<(i32, i32)>,
+    ) -> Result<Option<f32>, String> {
+        let mut crosshair = None; // how far up the wall the center of the screen is, if it's covered
+                                  //hold difference in distance
         let difference_bottom_y = b2 - b1;
         let difference_top_y = t2 - t1;
         let xs = x1;
@@ -98,12 +311,7 @@ impl Renderer {
         let mut x1_clipped = x1;
         let mut x2_clipped = x2;
         //clip x
-
-        // horizontal texture
-        let mut horizontal_texture = 0.0;
-        let h_step = (wall.texture.unwrap().width as f32) * wall.u / (x2 - x1);
         if x1_clipped < 0.0 {
-            horizontal_texture -= h_step as f32 * x1;
             x1_clipped = 0.0
         }
         if x2 < 0.0 {
@@ -125,11 +333,7 @@ impl Renderer {
             let mut y1_clipped = y1;
             let mut y2_clipped = y2;
 
-            // vertical texture
-            let mut vertical_texture = 0.0;
-            let v_step = (wall.texture.unwrap().height as f32) * wall.v / (y2 - y1);
             if y1 < 0.0 {
-                vertical_texture -= v_step as f32 * y1;
                 y1_clipped = 0.0;
             }
             if y2 < 0.0 {
@@ -144,6 +348,12 @@ impl Renderer {
 
             match cycle {
                 0 => {
+                    if x == HALF_WIDTH as i32
+                        && y1_clipped <= HALF_HEIGHT as f32
+                        && (HALF_HEIGHT as f32) < y2_clipped
+                    {
+                        crosshair = Some((HALF_HEIGHT as f32 - y1) / one_if_none(y2 - y1));
+                    }
                     // on the first pass we collect the points for the surface we want to draw
                     if sector.surface == Some(Surface::BottomScan) {
                         sector.surface_points[x as usize] = y1_clipped as u32;
@@ -151,40 +361,96 @@ impl Renderer {
                     if sector.surface == Some(Surface::TopScan) {
                         sector.surface_points[x as usize] = y2_clipped as u32;
                     } // ceiling points
+                    let depth = match sector.top_height - sector.bottom_height {
+                        0 => sector.distance,
+                        height => height.abs() as f32 * self.focal / one_if_none((y2 - y1).abs()),
+                    }; // a wall's height on screen shrinks with how far away it is
+                    let light = light_level(sector.light, depth, wall.contrast());
+                    let deferred = wall.see_through() && !self.masked_pass; // drawn later by draw_masked
+                    let across = (x as f32 - xs) / difference_x; // how far along the wall this column is
                     for y in y1_clipped as i32..y2_clipped as i32 {
-                        if wall.texture.is_some() {
-                            let height = wall.texture.unwrap().height as f32;
-                            let width = wall.texture.unwrap().width as f32;
-                            let pixel = (vertical_texture.trunc() % height) * width
-                                + (horizontal_texture.trunc() % width);
-                            let pixel_bytes =
-                                wall.texture.unwrap().data[pixel as usize].to_le_bytes();
-                            let pixel_color = Color {
-                                r: pixel_bytes[0],
-                                g: pixel_bytes[1],
-                                b: pixel_bytes[2],
-                                a: pixel_bytes[3],
-                            };
-
-                            self.draw_dot(x as f32, y as f32, pixel_color)?;
-                            vertical_texture += v_step as f32;
+                        if deferred {
+                            break;
+                        }
+                        let up = (y as f32 - y1) / one_if_none(y2 - y1); // how far up the wall this pixel is
+                        let height = sector.bottom_height as f32
+                            + up * (sector.top_height - sector.bottom_height) as f32;
+                        let slot = match wall.slot(height, neighbour) {
+                            Some(slot) => slot,
+                            _ => continue,
+                        }; // the part shared with the neighbouring sector is left open
+                        let mut pixel_color = match slot.texture {
+                            Some(texture) => {
+                                let (texture, scroll_u, scroll_v) =
+                                    textures::animate(texture, player.level.clock);
+                                let ((start_u, start_v), (span_u, span_v)) =
+                                    wall.mapping(slot, sector.bottom_height, sector.top_height);
+                                let (u, v) = (
+                                    start_u + across * span_u + scroll_u,
+                                    start_v + up * span_v + scroll_v,
+                                );
+                                let step = (span_u.abs() * texture.width as f32
+                                    / one_if_none(difference_x.abs()))
+                                .max(
+                                    span_v.abs() * texture.height as f32
+                                        / one_if_none((y2 - y1).abs()),
+                                ); // how many texels this column skips for every pixel
+                                match (self.mipmapping, self.bilinear) {
+                                    (true, _) if step > 1.0 => sample(texture.mip(step), u, v),
+                                    (_, true) if step <= 1.0 => {
+                                        mipmaps::sample_bilinear(texture, u, v)
+                                    }
+                                    _ => sample(texture, u, v),
+                                }
+                            }
+                            _ => color, // walls without a texture are drawn in their color
+                        };
+                        if self.masked_pass {
+                            if self.depth_buffer[y as usize * SCREEN_WIDTH + x as usize] < depth
+                                || (wall.masked && pixel_color.a == 0)
+                            {
+                                continue;
+                            } // hidden behind something closer, or a hole in the texture
+                            pixel_color.a =
+                                (pixel_color.a as u32 * wall.opacity as u32 / 255) as u8;
+                            if pixel_color.a == 255 {
+                                self.set_depth(x, y, depth);
+                            } // only solid texels hide what is further away
+                        } else {
+                            pixel_color.a = 255;
+                            self.set_depth(x, y, depth);
                         }
+                        self.draw_dot(x as f32, y as f32, shade(pixel_color, light))?;
                     }
-                    horizontal_texture += h_step as f32;
                 }
                 1 => {
+                    let mut draw_color = color;
+                    let mut surface_height = player.position.z;
                     if sector.surface == Some(Surface::BottomScan) {
                         y2_clipped = sector.surface_points[x as usize] as f32;
-                        //draw_color = sector.bottom_color;
+                        draw_color = sector.bottom_color;
+                        surface_height = sector.bottom_height;
                     }
                     if sector.surface == Some(Surface::TopScan) {
                         y1_clipped = sector.surface_points[x as usize] as f32;
-                        //draw_color = sector.top_color;
+                        draw_color = sector.top_color;
+                        surface_height = sector.top_height;
+                    }
+                    if x == HALF_WIDTH as i32
+                        && sector.surface.is_some()
+                        && y1_clipped <= HALF_HEIGHT as f32
+                        && (HALF_HEIGHT as f32) < y2_clipped
+                    {
+                        crosshair = Some(0.0);
                     }
+                    if sector.sky && sector.surface == Some(Surface::TopScan) {
+                        self.draw_sky(player, x, y1_clipped as i32, y2_clipped as i32)?;
+                        continue;
+                    } // sky sectors show the sky where their ceiling would be
 
                     let x_offset = SCREEN_WIDTH as f32 / 2.0;
                     let y_offset = SCREEN_HEIGHT as f32 / 2.0;
-                    let fov = 700.0;
+                    let fov = self.focal;
                     let x2 = x - x_offset as i32;
                     let wall_offset = 0.0;
 
@@ -192,6 +458,21 @@ impl Renderer {
                     let y_start = y1_clipped - y_offset;
                     let y_end = y2_clipped - y_offset;
                     for y in y_start as u32..y_end as u32 {
+                        let depth = (player.position.z - surface_height).abs() as f32 * self.focal
+                            / (y as f32).max(1.0); // rows nearer the horizon are further away
+                        let light = light_level(sector.light, depth, 0);
+                        self.set_depth(x, (y as f32 + y_offset) as i32, depth);
+                        let (texture, scroll_u, scroll_v) = match sector.surface_texture {
+                            Some(texture) => textures::animate(texture, player.level.clock),
+                            _ => {
+                                self.draw_dot(
+                                    x2 as f32 + x_offset,
+                                    y as f32 + y_offset,
+                                    shade(draw_color, light),
+                                )?;
+                                continue;
+                            }
+                        }; // surfaces without a texture are drawn in the sector's floor or ceiling color
                         let mut z = y as f32;
                         if z as f32 == 0.0 {
                             z = 0.0001;
@@ -199,24 +480,29 @@ impl Renderer {
                         let fx = x2_clipped / z * move_z;
                         let fy = fov / z * move_z;
                         let rx = fx * sine(player.angle_h) - fy * cosine(player.angle_h)
-                            + (player.position.y / 60 * 3) as f32;
+                            + (player.position.y / 60 * 3) as f32
+                            + scroll_u * texture.width as f32;
                         let ry = fx * cosine(player.angle_h)
                             + fy * sine(player.angle_h)
-                            + (player.position.x / 60 * 3) as f32;
-                        let pixel = (wall.texture.unwrap().height as f32
-                            - (ry.trunc() % wall.texture.unwrap().height as f32))
+                            + (player.position.x / 60 * 3) as f32
+                            + scroll_v * texture.height as f32; // scrolling surfaces slide along in texels
+                        let pixel = (texture.height as f32 - (ry.trunc() % texture.height as f32))
                             - 1.0
-                                * (wall.texture.unwrap().width as f32
-                                    - (rx.trunc() % wall.texture.unwrap().width as f32)
+                                * (texture.width as f32
+                                    - (rx.trunc() % texture.width as f32)
                                     - 1.0);
-                        let pixel_bytes = wall.texture.unwrap().data[pixel as usize].to_be_bytes();
+                        let pixel_bytes = texture.data[pixel as usize].to_be_bytes();
                         let pixel_color = Color {
                             r: pixel_bytes[3],
                             g: pixel_bytes[2],
                             b: pixel_bytes[1],
                             a: pixel_bytes[0],
                         };
-                        self.draw_dot(x2 as f32 + x_offset, y as f32 + y_offset, pixel_color)?;
+                        self.draw_dot(
+                            x2 as f32 + x_offset,
+                            y as f32 + y_offset,
+                            shade(pixel_color, light),
+                        )?;
                     }
                 }
                 _ => {
@@ -228,16 +514,136 @@ impl Renderer {
             //     self.draw_dot(x as f32, y as f32, draw_color)?;
             // }
         }
-        Ok(())
+        Ok(crosshair)
     } // Draws a given wall in 3D perspective accounting for player position
 
-    pub fn draw_first_person(&mut self, gun_texture: Texture) -> Result<(), String> {
-        let mut horizontal_texture: f32 = 0.0;
+    fn set_depth(&mut self, x: i32, y: i32, depth: f32) {
+        if (0..SCREEN_WIDTH as i32).contains(&x) && (0..SCREEN_HEIGHT as i32).contains(&y) {
+            self.depth_buffer[y as usize * SCREEN_WIDTH + x as usize] = depth;
+        }
+    } // remembers how far away the thing drawn at a pixel is
+
+    fn draw_sky(&mut self, player: &PlayerInfo, x: i32, y1: i32, y2: i32) -> Result<(), String> {
+        let texture = textures::SKY_TEXTURE;
+        let angle = player.angle_h as f32
+            + ((x - HALF_WIDTH as i32) as f32 / self.focal)
+                .atan()
+                .to_degrees(); // which way this column of the screen looks
+        let u = (angle.rem_euclid(360.0) / 360.0 * texture.width as f32 * SKY_REPEATS) as u32
+            % texture.width;
+        for y in y1..y2 {
+            let v = ((y as f32 / HALF_HEIGHT as f32 * texture.height as f32) as u32)
+                .min(texture.height - 1); // the bottom of the sky meets the horizon
+            let pixel_bytes = texture.data[(v * texture.width + u) as usize].to_le_bytes();
+            self.draw_dot(
+                x as f32,
+                y as f32,
+                Color::RGB(pixel_bytes[0], pixel_bytes[1], pixel_bytes[2]),
+            )?;
+            self.set_depth(x, y, f32::INFINITY);
+        }
+        Ok(())
+    } // draws a column of the sky; it turns with the player but never gets closer, so it looks infinitely far away
+
+    pub fn draw_sprites(&mut self, player: &PlayerInfo) -> Result<(), String> {
+        let mut sprites: Vec<(f32, f32, Sprite)> = Vec::new();
+        let mut effects = player.weapons.sprites();
+        effects.extend(player.level.actors.iter().map(|actor| actor.sprite()));
+        for sprite in player.level.sprites.iter().chain(effects.iter()) {
+            let x = sprite.x - player.position.x as f32;
+            let y = sprite.y - player.position.y as f32;
+            let world_x = x * cosine(player.angle_h) - y * sine(player.angle_h);
+            let world_y = y * cosine(player.angle_h) + x * sine(player.angle_h);
+            if world_y >= 1.0 {
+                sprites.push((world_x, world_y, *sprite));
+            } // sprites behind the player aren't drawn
+        }
+        sprites.sort_by(|a, b| b.1.total_cmp(&a.1)); // furthest first so nearer sprites are drawn over them
+
+        for (world_x, depth, sprite) in sprites {
+            let texture = sprite.texture;
+            let width = sprite.height * texture.width as f32 / texture.height as f32;
+            let center = world_x * self.focal / depth + HALF_WIDTH as f32;
+            let left = center - width / 2.0 * self.focal / depth;
+            let right = center + width / 2.0 * self.focal / depth;
+            let bottom =
+                (sprite.z - player.position.z as f32) * self.focal / depth + HALF_HEIGHT as f32;
+            let top = (sprite.z + sprite.height - player.position.z as f32) * self.focal / depth
+                + HALF_HEIGHT as f32;
+            let light = match player.level.sector_at(sprite.x, sprite.y) {
+                Some(s) => light_level(player.level.sectors[s].light, depth, 0),
+                _ => light_level(255, depth, 0),
+            }; // sprites are lit by the sector they stand in
+
+            for x in (left.max(0.0) as i32)..(right.min(SCREEN_WIDTH as f32) as i32) {
+                let column = ((x as f32 - left) / (right - left) * texture.width as f32) as usize;
+                for y in (bottom.max(0.0) as i32)..(top.min(SCREEN_HEIGHT as f32) as i32) {
+                    if self.depth_buffer[y as usize * SCREEN_WIDTH + x as usize] < depth {
+                        continue;
+                    } // hidden behind a wall or surface
+                    let row =
+                        ((y as f32 - bottom) / (top - bottom) * texture.height as f32) as usize;
+                    let pixel = row.min(texture.height as usize - 1) * texture.width as usize
+                        + column.min(texture.width as usize - 1);
+                    let pixel_bytes = texture.data[pixel].to_le_bytes();
+                    if pixel_bytes[3] == 0 {
+                        continue;
+                    } // transparent texels let whatever is behind show through
+                    let pixel_color = Color {
+                        r: pixel_bytes[0],
+                        g: pixel_bytes[1],
+                        b: pixel_bytes[2],
+                        a: pixel_bytes[3],
+                    };
+                    self.draw_dot(x as f32, y as f32, shade(pixel_color, light))?;
+                    self.set_depth(x, y, depth); // so see-through walls in front of the sprite still cover it
+                }
+            }
+        }
+        Ok(())
+    } // draws every sprite in the level as a billboard facing the player, hidden by anything in front of it
+
+    fn draw_masked(&mut self, player: &mut PlayerInfo) -> Result<(), String> {
+        let mut masked = std::mem::take(&mut self.masked);
+        masked.sort_by(|a, b| b.distance.total_cmp(&a.distance)); // furthest first
+        self.masked_pass = true;
+        self.canvas.set_blend_mode(BlendMode::Blend);
+        for m in masked.iter_mut() {
+            self.draw_wall(
+                player,
+                m.x1,
+                m.x2,
+                m.b1,
+                m.b2,
+                m.t1,
+                m.t2,
+                0,
+                m.color,
+                &mut m.sector,
+                &mut m.wall,
+                m.neighbour,
+            )?;
+        }
+        self.canvas.set_blend_mode(BlendMode::None);
+        self.masked_pass = false;
+        Ok(())
+    } // draws see-through walls back to front, hidden by anything solid in front of them
+
+    pub fn draw_first_person(&mut self, player: &PlayerInfo) -> Result<(), String> {
+        let gun_texture = player.weapons.weapon().texture;
+        let gun_tint = player.weapons.weapon().tint;
+        let bob = player.weapons.bob as i32 * 20;
+        let x_offset = sine(bob) * 0.02 * SCREEN_WIDTH as f32; // the weapon sways side to side as the player walks
+        let y_offset = cosine(2 * bob).abs() * 0.02 * SCREEN_HEIGHT as f32
+            + player.weapons.recoil as f32 * 0.01 * SCREEN_HEIGHT as f32; // ... and kicks down when it is fired
+        let left = 0.6 * SCREEN_WIDTH as f32 + x_offset;
+        let top = HALF_HEIGHT as f32 + y_offset;
         let h_step = gun_texture.width as f32 / (0.3 * SCREEN_WIDTH as f32);
-        let mut vertical_texture: f32 = 0.0;
         let v_step = (gun_texture.height as f32) / (HALF_HEIGHT) as f32;
-        for x in (0.6 * SCREEN_WIDTH as f32) as usize..(0.9 * SCREEN_WIDTH as f32) as usize {
-            for y in (HALF_HEIGHT)..SCREEN_HEIGHT {
+        for x in left.max(0.0) as usize..(left + 0.3 * SCREEN_WIDTH as f32) as usize {
+            let horizontal_texture = (x as f32 - left) * h_step;
+            for y in top as usize..SCREEN_HEIGHT {
+                let vertical_texture = (y as f32 - top) * v_step;
                 let height = gun_texture.height as f32;
                 let width = gun_texture.width as f32;
                 let pixel = (vertical_texture.trunc() % height) * width
@@ -249,28 +655,24 @@ impl Renderer {
                     b: pixel_bytes[2],
                     a: pixel_bytes[3],
                 };
-                if pixel_color == Color::RGBA(0, 0, 0, 0) {
-                } else if x > (0.7 * SCREEN_WIDTH as f32) as usize
-                    && y < (0.6 * SCREEN_HEIGHT as f32) as usize
-                {
-                } else {
-                    self.draw_dot(x as f32, y as f32, pixel_color)?;
-                }
-                vertical_texture += v_step as f32;
+                if pixel_color.a != 0 {
+                    self.draw_dot(x as f32, y as f32, tint(pixel_color, gun_tint))?;
+                } // transparent texels are skipped
             }
-            horizontal_texture += h_step as f32;
         }
         Ok(())
-    }
+    } // draws the weapon the player is holding over the 3D view
 
     pub fn draw3d(&mut self, player_raw: &mut PlayerInfo) -> Result<(), String> {
         // Master function for the player perspective;
-        self.draw_mode = DrawMode::Draw3D;
         let mut player = PlayerInfo::distances(player_raw);
+        self.pick = None;
+        self.depth_buffer.fill(f32::INFINITY);
+        self.masked = Vec::new();
 
-        for s in 0..player.level.number_of_sectors {
+        for s in draw_order(&player.level.sectors) {
             // draws sectors/walls from level.rs in 3D as the player sees it
-            let mut sector = player.level.sectors[s as usize];
+            let mut sector = player.level.sectors[s];
             sector.distance = 0.0;
             let mut number_of_cycles = 1;
             if player.position.z < sector.bottom_height {
@@ -292,6 +694,15 @@ impl Renderer {
             for cycle in 0..number_of_cycles {
                 for w in sector.wall_start..sector.wall_end {
                     let mut wall = player.level.walls[w as usize];
+                    let neighbour = match cycle {
+                        0 => player.level.neighbour_of_wall(w as usize).map(|n| {
+                            (
+                                player.level.sectors[n].bottom_height,
+                                player.level.sectors[n].top_height,
+                            )
+                        }),
+                        _ => None,
+                    }; // where a sector is on the other side only the parts above & below it are drawn
                     let color = wall.color;
                     //oftset bottom 2 points by player:
                     let mut x1 = wall.x1 as i32 - player.position.x;
@@ -374,15 +785,54 @@ impl Renderer {
                         );
                     }
                     //screen x:
-                    let screen_x1 = world_x1 * 700.0 / world_y1 + HALF_WIDTH as f32;
-                    let screen_x2 = world_x2 * 700.0 / world_y2 + HALF_WIDTH as f32;
+                    let screen_x1 = world_x1 * self.focal / world_y1 + HALF_WIDTH as f32;
+                    let screen_x2 = world_x2 * self.focal / world_y2 + HALF_WIDTH as f32;
 
                     //screen y:
-                    let screen_y1 = world_z1 * 700.0 / world_y1 + HALF_HEIGHT as f32;
-                    let screen_y2 = world_z2 * 700.0 / world_y2 + HALF_HEIGHT as f32;
-                    let screen_y3 = world_z3 * 700.0 / world_y3 + HALF_HEIGHT as f32;
-                    let screen_y4 = world_z4 * 700.0 / world_y4 + HALF_HEIGHT as f32;
-                    self.draw_wall(
+                    let screen_y1 = world_z1 * self.focal / world_y1 + HALF_HEIGHT as f32;
+                    let screen_y2 = world_z2 * self.focal / world_y2 + HALF_HEIGHT as f32;
+                    let screen_y3 = world_z3 * self.focal / world_y3 + HALF_HEIGHT as f32;
+                    let screen_y4 = world_z4 * self.focal / world_y4 + HALF_HEIGHT as f32;
+                    if cycle == 0 && wall.see_through() && screen_x1 < screen_x2 && !self.wireframe
+                    {
+                        self.masked.push(MaskedWall {
+                            x1: screen_x1,
+                            x2: screen_x2,
+                            b1: screen_y1,
+                            b2: screen_y2,
+                            t1: screen_y3,
+                            t2: screen_y4,
+                            color,
+                            sector,
+                            wall,
+                            neighbour,
+                            distance: distance(
+                                0.0,
+                                0.0,
+                                (world_x1 + world_x2) / 2.0,
+                                (world_y1 + world_y2) / 2.0,
+                            ),
+                        });
+                    } // drawn after everything solid so they can be seen through
+                    if cycle == 0 && screen_x1.max(0.0) < screen_x2.min(SCREEN_WIDTH as f32) {
+                        self.automap.seen.insert(w as usize);
+                    } // walls facing the player & on screen show up on the automap
+                    if self.wireframe {
+                        if cycle == 0 {
+                            self.canvas.set_draw_color(color);
+                            for ((ax, ay), (bx, by)) in [
+                                ((screen_x1, screen_y1), (screen_x2, screen_y2)),
+                                ((screen_x1, screen_y3), (screen_x2, screen_y4)),
+                                ((screen_x1, screen_y1), (screen_x1, screen_y3)),
+                                ((screen_x2, screen_y2), (screen_x2, screen_y4)),
+                            ] {
+                                self.canvas
+                                    .draw_line((ax as i32, ay as i32), (bx as i32, by as i32))?;
+                            }
+                        }
+                        continue;
+                    } // only the edges of each wall, with nothing filled in
+                    let crosshair = self.draw_wall(
                         &mut player,
                         screen_x1,
                         screen_x2,
@@ -394,12 +844,27 @@ impl Renderer {
                         color,
                         &mut sector,
                         &mut wall,
+                        neighbour,
                     )?;
+                    self.pick = match crosshair {
+                        Some(height) => match (cycle, sector.surface) {
+                            (0, _) => Some(Pick::Wall {
+                                sector: s,
+                                wall: w as usize,
+                                height,
+                            }),
+                            (_, Some(Surface::BottomScan)) => Some(Pick::Floor { sector: s }),
+                            (_, Some(Surface::TopScan)) => Some(Pick::Ceiling { sector: s }),
+                            _ => self.pick,
+                        },
+                        _ => self.pick,
+                    }; // sectors are drawn back to front so the last thing under the crosshair is what the player sees
                 }
                 sector.distance /= (sector.wall_end - sector.wall_start) as f32;
             }
         }
-        self.draw_first_person(WATER_GUN_TEXTURE)?;
+        self.draw_sprites(player)?;
+        self.draw_masked(player)?;
         Ok(())
     }
     // draw3d functions:
@@ -438,3 +903,55 @@ impl Renderer {
         *z1 = *z1 + s * (z2 - (*z1));
     } //prevents overdrawing behind the player
 }
+
+pub fn editor_area(draw_mode: DrawMode) -> Rect {
+    match draw_mode {
+        DrawMode::Split => Rect::new(0, 0, HALF_WIDTH as u32, SCREEN_HEIGHT as u32),
+        _ => Rect::new(0, 0, SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32),
+    }
+} // the part of the screen the 2D editor has; the split view gives the left half to it
+
+pub fn preview_area() -> Rect {
+    Rect::new(
+        HALF_WIDTH as i32,
+        0,
+        (SCREEN_WIDTH - HALF_WIDTH) as u32,
+        SCREEN_HEIGHT as u32,
+    )
+} // where the 3D preview is drawn in the split view
+
+pub fn light_level(light: u8, depth: f32, contrast: i32) -> u8 {
+    let light = (light as f32 - depth * LIGHT_FALLOFF) as i32 + contrast;
+    let band = 256 / LIGHT_BANDS;
+    (light.clamp(0, 255) / band * band + band - 1) as u8
+} // how bright something in a sector is once distance and the wall's facing are taken into account
+
+pub fn sample(texture: Texture, u: f32, v: f32) -> Color {
+    let column = ((u * texture.width as f32) as i32).rem_euclid(texture.width as i32) as u32;
+    let row = ((v * texture.height as f32) as i32).rem_euclid(texture.height as i32) as u32;
+    let pixel_bytes = texture.data[(row * texture.width + column) as usize].to_le_bytes();
+    Color {
+        r: pixel_bytes[0],
+        g: pixel_bytes[1],
+        b: pixel_bytes[2],
+        a: pixel_bytes[3],
+    }
+} // the texel at a point on a texture, where 1.0 is the whole width or height; it repeats past that
+
+pub fn shade(color: Color, light: u8) -> Color {
+    Color::RGBA(
+        (color.r as u32 * light as u32 / 255) as u8,
+        (color.g as u32 * light as u32 / 255) as u8,
+        (color.b as u32 * light as u32 / 255) as u8,
+        color.a,
+    )
+} // darkens a color by a light level
+
+pub fn tint(color: Color, tint: Color) -> Color {
+    Color::RGBA(
+        (color.r as u32 * tint.r as u32 / 255) as u8,
+        (color.g as u32 * tint.g as u32 / 255) as u8,
+        (color.b as u32 * tint.b as u32 / 255) as u8,
+        color.a,
+    )
+} // colors a color in, channel by channel; white leaves it as it is
@@ -0,0 +1,518 @@
+pub const CONSOLE_1D_WIDTH: u32 = 64;
+
+pub const CONSOLE_1D_HEIGHT: u32 = 64;
+
+pub const CONSOLE_1D_ARRAY: [u32; 4096] = [
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff282c34, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff67655b, 0xff545249,
+    0xff545249, 0xff282c34, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff84806d, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff3d3849, 0xffa59e80, 0xff67655b, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff3d3849, 0xffa59e80, 0xff67655b, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff67655b, 0xff545249,
+    0xff545249, 0xff282c34, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff3d3849, 0xffa59e80, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff3d3849, 0xffa59e80, 0xff67655b, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff84806d, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff84806d, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff84806d, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff495262, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff84806d, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff181a1a,
+    0xff000000, 0xff67655b, 0xff545249, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff545249, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff495262, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff000000, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff000000, 0xffa59e80, 0xff67655b, 0xff495262, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xffbcbbad,
+    0xffbcbbad, 0xffbcbbad, 0xffbcbbad, 0xffbcbbad, 0xffa59e80, 0xffbcbbad, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff84806d, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xff84806d, 0xffa59e80, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d,
+    0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xffbcbbad, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xffbcbbad, 0xff67655b, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xffa59e80, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff495262, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffbcbbad, 0xff3d3849, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+    0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff545249, 0xff3d3849, 0xff545249, 0xff3d3849, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff3d3849,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xffbcbbad, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff343735, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffbcbbad, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff1e2818, 0xffa59e80, 0xff585443, 0xff38372f, 0xff38372f, 0xff38372f, 0xff38372f,
+    0xff38372f, 0xff262320, 0xff38372f, 0xff262320, 0xff262320, 0xff262320, 0xff262320, 0xff78dc5a,
+    0xff262320, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xffbcbbad, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff181a1a, 0xff585443, 0xff38372f, 0xff262320, 0xff262320, 0xff262320, 0xff262320,
+    0xff262320, 0xff262320, 0xff262320, 0xff262320, 0xff181a1a, 0xff262320, 0xff181a1a, 0xff262320,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff84806d,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff1e2818, 0xff38372f, 0xff262320, 0xff262320, 0xff262320, 0xff262320, 0xff262320,
+    0xff262320, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff84806d,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffbcbbad, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff181a1a, 0xff38372f, 0xff262320, 0xff262320, 0xff262320, 0xff181a1a, 0xff262320,
+    0xff78dc5a, 0xff262320, 0xff78dc5a, 0xff78dc5a, 0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a,
+    0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff181a1a, 0xff78dc5a, 0xff181a1a, 0xff78dc5a, 0xff78dc5a,
+    0xff78dc5a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xff84806d,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff1e2818, 0xff38372f, 0xff262320, 0xff262320, 0xff1e2818, 0xff262320, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff84806d,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff181a1a, 0xff262320, 0xff262320, 0xff181a1a, 0xff262320, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xff84806d,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff1e2818, 0xff38372f, 0xff262320, 0xff262320, 0xff78dc5a, 0xff1e2818, 0xff78dc5a,
+    0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff181a1a, 0xff262320, 0xff262320, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xff84806d,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff181a1a, 0xff181a1a, 0xff262320, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a,
+    0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff232424,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff3d3849, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff181a1a, 0xff181a1a, 0xff262320, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff1e2818, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a,
+    0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff1e2818, 0xff78dc5a,
+    0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff1e2818, 0xff262320, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffbcbbad,
+    0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff1e2818, 0xff1e2818, 0xff00b22d, 0xff1e2818, 0xff00c745, 0xff004c0f, 0xff00b22d,
+    0xff1e2818, 0xff00c745, 0xff1e2818, 0xff004c0f, 0xff00c745, 0xff1e2818, 0xff00c745, 0xff00802f,
+    0xff1e2818, 0xff00b22d, 0xff1e2818, 0xff004c0f, 0xff00b22d, 0xff00802f, 0xff1e2818, 0xff004c0f,
+    0xff1e2818, 0xff00c745, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffbcbbad,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff181a1a, 0xff181a1a, 0xff00c745, 0xff181a1a, 0xff004c0f, 0xff00b22d, 0xff00802f,
+    0xff181a1a, 0xff004c0f, 0xff181a1a, 0xff00c745, 0xff00802f, 0xff181a1a, 0xff00c745, 0xff00b22d,
+    0xff181a1a, 0xff00b22d, 0xff181a1a, 0xff00802f, 0xff181a1a, 0xff00c745, 0xff181a1a, 0xff00b22d,
+    0xff004c0f, 0xff00b22d, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff282c34, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a,
+    0xff78dc5a, 0xff78dc5a, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffbcbbad,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff282c34,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff181a1a, 0xff181a1a, 0xff00c745, 0xff181a1a, 0xff00b22d, 0xff004c0f, 0xff181a1a,
+    0xff00c745, 0xff181a1a, 0xff004c0f, 0xff00b22d, 0xff181a1a, 0xff00802f, 0xff181a1a, 0xff00c745,
+    0xff181a1a, 0xff00c745, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xffbcbbad,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff1e2818, 0xff1e2818, 0xff004c0f, 0xff00802f, 0xff00c745, 0xff00b22d, 0xff1e2818,
+    0xff00b22d, 0xff1e2818, 0xff00802f, 0xff00b22d, 0xff1e2818, 0xff00c745, 0xff00b22d, 0xff004c0f,
+    0xff1e2818, 0xff00b22d, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818,
+    0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000, 0xffbcbbad,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a,
+    0xff181a1a, 0xff78dc5a, 0xff78dc5a, 0xff78dc5a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff78dc5a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff000000, 0xff000000, 0xffbcbbad,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff343735, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff343735, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff1e2818, 0xff000000,
+    0xff1e2818, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff343735, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff181a1a, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xff84806d, 0xff67655b, 0xff84806d,
+    0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d,
+    0xff84806d, 0xffa59e80, 0xff84806d, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffbcbbad, 0xffbcbbad, 0xffbcbbad, 0xffbcbbad, 0xffbcbbad, 0xffa59e80, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff84806d, 0xff67655b, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xffa59e80, 0xff67655b, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff181a1a, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff3d3849, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff3d3849,
+    0xff67655b, 0xff3d3849, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249, 0xff545249,
+    0xff545249, 0xff3d3849, 0xff545249, 0xff3d3849, 0xff545249, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+    0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+    0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+    0xff3d3849, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff000000,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff84806d, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff181a1a,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff232424, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff000000,
+    0xff343735, 0xff232424, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xff67655b, 0xffa59e80, 0xff67655b, 0xffa59e80, 0xff67655b, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xff67655b, 0xffa59e80, 0xff67655b, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffa59e80, 0xff67655b, 0xffa59e80, 0xff67655b, 0xff67655b, 0xff67655b, 0xff000000,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff181a1a, 0xff232424, 0xff181a1a, 0xff181a1a, 0xff181a1a,
+    0xff343735, 0xff181a1a, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff343735, 0xff232424, 0xff181a1a, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff181a1a, 0xff232424, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff000000, 0xff343735, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff343735, 0xff181a1a, 0xff181a1a, 0xff232424, 0xff343735, 0xff232424, 0xff232424, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff181a1a, 0xff000000, 0xff343735, 0xff232424, 0xff232424, 0xff181a1a, 0xff343735,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xff232424, 0xff000000, 0xff000000, 0xff232424, 0xff232424, 0xff181a1a, 0xff000000, 0xff232424,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xff84806d, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xff84806d, 0xffa59e80,
+    0xff84806d, 0xffa59e80, 0xff84806d, 0xffa59e80, 0xff84806d, 0xffa59e80, 0xff84806d, 0xff84806d,
+    0xff84806d, 0xff84806d, 0xff84806d, 0xff84806d, 0xffa59e80, 0xff84806d, 0xffa59e80, 0xff84806d,
+    0xffa59e80, 0xff84806d, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80,
+    0xffa59e80, 0xff84806d, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xffa59e80, 0xff84806d, 0xffa59e80,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xffa59e80, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff545249, 0xff67655b,
+    0xff545249, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff545249, 0xff545249, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff545249, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff545249, 0xff545249,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff3d3849, 0xff3d3849, 0xff67655b, 0xff67655b, 0xff545249, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b,
+    0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff67655b, 0xff545249, 0xff67655b, 0xff545249,
+    0xff3d3849, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff3d3849,
+    0xff1e243d, 0xff3d3849, 0xff1e243d, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+    0xff3d3849, 0xff3d3849, 0xff1e243d, 0xff3d3849, 0xff1e243d, 0xff3d3849, 0xff181a1a, 0xff3d3849,
+    0xff181a1a, 0xff3d3849, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff181a1a, 0xff3d3849, 0xff181a1a,
+    0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+    0xff1e243d, 0xff3d3849, 0xff1e243d, 0xff3d3849, 0xff3d3849, 0xff1e243d, 0xff3d3849, 0xff1e243d,
+    0xff3d3849, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff1e243d, 0xff3d3849,
+    0xff1e243d, 0xff3d3849, 0xff1e243d, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849, 0xff3d3849,
+];
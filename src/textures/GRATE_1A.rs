@@ -0,0 +1,134 @@
+pub const GRATE_1A_WIDTH: u32 = 32;
+
+pub const GRATE_1A_HEIGHT: u32 = 32;
+
+pub const GRATE_1A_ARRAY: [u32; 1024] = [
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696,
+    0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xffa59696, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e,
+    0xffa59696, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff7d6e6e, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0xffa59696, 0xff7d6e6e, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+    0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a, 0xff695a5a,
+];
@@ -0,0 +1,518 @@
+pub const SKY_WIDTH: u32 = 128;
+
+pub const SKY_HEIGHT: u32 = 32;
+
+pub const SKY_ARRAY: [u32; 4096] = [
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28, 0xffc85a28,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b, 0xffc95d2b,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f, 0xffcb612f,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433, 0xffcc6433,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837, 0xffce6837,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b, 0xffd06b3b,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f, 0xffd16f3f,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243, 0xffd37243,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646, 0xffd47646,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a, 0xffd6794a,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e, 0xffd87d4e,
+    0xffd98152, 0xffda8457, 0xffdb885c, 0xffdb895d, 0xffda8659, 0xffd98153, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98355, 0xffda865a, 0xffda875a, 0xffd98456, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98153, 0xffd98253, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152, 0xffd98152,
+    0xffdd8c62, 0xffdf966f, 0xffe19c77, 0xffe19c79, 0xffe09873, 0xffde9067, 0xffdb875a, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdd8b61,
+    0xffdf946c, 0xffe09973, 0xffe09974, 0xffdf946d, 0xffdd8c61, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdc885c, 0xffdc895d, 0xffdb8659, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdd8c61, 0xffde9068, 0xffde9169, 0xffdd8e64, 0xffdc885c, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456, 0xffdb8456,
+    0xffe29e79, 0xffe5ac8d, 0xffe8b59a, 0xffe8b69b, 0xffe7b093, 0xffe3a481, 0xffdf956d, 0xffdc885b,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdd8f64, 0xffe19c77,
+    0xffe5a989, 0xffe7b093, 0xffe7b194, 0xffe5aa8a, 0xffe19d78, 0xffdd8f64, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc895c, 0xffde9168,
+    0xffe09770, 0xffe09871, 0xffdf946b, 0xffdd8b5f, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffde9167, 0xffe19d78, 0xffe3a482, 0xffe4a583, 0xffe2a07c, 0xffe09770, 0xffdd8d61,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a,
+    0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdc885a, 0xffdd8e63,
+    0xffe7b093, 0xffedc4af, 0xfff0d0c1, 0xfff0d2c3, 0xffeec9b7, 0xffeab99f, 0xffe4a583, 0xffe09369,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffe29b76, 0xffe7ae91,
+    0xffecc0a9, 0xffeecab8, 0xffeecbb9, 0xffecc1ab, 0xffe7b093, 0xffe29b76, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffdf8f64, 0xffdf9268, 0xffdf8e63, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffe0936a, 0xffe39f7b,
+    0xffe5a787, 0xffe6a989, 0xffe4a380, 0xffe1976f, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8d61, 0xffe39f7a, 0xffe7af91, 0xffeab9a0, 0xffeabaa2, 0xffe8b498, 0xffe5a786, 0xffe19972,
+    0xffde8d62, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e,
+    0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffde8b5e, 0xffe29a74,
+    0xffedc3ae, 0xfff3dcd2, 0xfff7ece8, 0xfff8efec, 0xfff5e3dc, 0xfff0cebd, 0xffe9b599, 0xffe39e77,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe19469, 0xffe6a988, 0xffecc1aa,
+    0xfff2d7ca, 0xfff5e4dd, 0xfff6e5de, 0xfff2d9cd, 0xffedc3ad, 0xffe6a988, 0xffe09267, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe09165, 0xffe29971, 0xffe39c76, 0xffe29870, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe09164, 0xffe49f79, 0xffe7ae8f,
+    0xffeab89e, 0xffebbaa0, 0xffe9b295, 0xffe5a37f, 0xffe09165, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe2976e, 0xffe7ad8e, 0xffedc2ab, 0xfff0cfbe, 0xfff0d0c1, 0xffeec8b4, 0xffeab89d, 0xffe6a684,
+    0xffe2976e, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62,
+    0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe08f62, 0xffe09063, 0xffe6a886,
+    0xfff1d3c4, 0xfff9f1f0, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff4e0d7, 0xffedc2ab, 0xffe6a783,
+    0xffe19367, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe39b72, 0xffe9b497, 0xfff0d1c1,
+    0xfff7ebe6, 0xfffaf5f5, 0xfffaf5f5, 0xfff8edea, 0xfff1d3c4, 0xffe9b497, 0xffe29970, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe2976d, 0xffe5a27c, 0xffe5a581, 0xffe4a07a, 0xffe1956a, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe2976d, 0xffe6a886, 0xffebba9f,
+    0xffeec6b1, 0xffeec9b5, 0xffecbfa7, 0xffe7ad8c, 0xffe2986e, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe49f78, 0xffebb99e, 0xfff1d2c2, 0xfff5e1d8, 0xfff5e3db, 0xfff2d9cc, 0xffeec6b1, 0xffe8b192,
+    0xffe49f78, 0xffe19367, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265,
+    0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe19265, 0xffe2966c, 0xffe9b395,
+    0xfff4dfd4, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff8ece9, 0xfff0ccb9, 0xffe9ae8d,
+    0xffe3996e, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe5a27a, 0xffecbda2, 0xfff4dcd0,
+    0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff4ded3, 0xffecbda2, 0xffe5a078, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe49e74, 0xffe7a985, 0xffe8ad8b, 0xffe7a783, 0xffe49b71, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe49d74, 0xffe9b08f, 0xffeec3ab,
+    0xfff1d0bf, 0xfff1d3c3, 0xffefc9b4, 0xffeab597, 0xffe59e75, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe6a681, 0xffedc2ab, 0xfff4ddd1, 0xfff8edea, 0xfff8efed, 0xfff6e4dc, 0xfff1d0bf, 0xffebb99d,
+    0xffe6a681, 0xffe3996e, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669,
+    0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe39669, 0xffe49d73, 0xffecbba0,
+    0xfff5e3da, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff9f1ef, 0xfff1d0bf, 0xffeab292,
+    0xffe59d72, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe7a57f, 0xffeec1a8, 0xfff5e0d6,
+    0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff5e2d9, 0xffeec1a8, 0xffe7a37d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe6a179, 0xffe9ac8a, 0xffeab090, 0xffe9ab88, 0xffe69f76, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe6a179, 0xffebb495, 0xffefc7b1,
+    0xfff2d4c5, 0xfff3d7c9, 0xfff0cdba, 0xffecb99c, 0xffe7a27a, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe8a986, 0xffefc6b0, 0xfff5e1d7, 0xfff9f1f0, 0xfff9f4f3, 0xfff7e8e3, 0xfff2d4c4, 0xffedbda2,
+    0xffe8aa86, 0xffe59d73, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d,
+    0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe5996d, 0xffe6a078, 0xffedbfa5,
+    0xfff5e0d6, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff8ede9, 0xfff1cfbc, 0xffebb393,
+    0xffe6a075, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe8a881, 0xffeec1a7, 0xfff4ded2,
+    0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff5e0d5, 0xffeec1a7, 0xffe8a67f, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe7a47c, 0xffeaae8b, 0xffeab291, 0xffe9ad89, 0xffe7a279, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe7a47c, 0xffebb595, 0xffefc7b0,
+    0xfff2d3c2, 0xfff2d5c6, 0xfff0ccb8, 0xffecba9c, 0xffe7a57d, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe9ac87, 0xffefc6af, 0xfff4ded3, 0xfff8eeea, 0xfff8f0ee, 0xfff6e5de, 0xfff2d3c2, 0xffedbea2,
+    0xffe9ac87, 0xffe6a075, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71,
+    0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe69d71, 0xffe7a37a, 0xffedbfa5,
+    0xfff3d8ca, 0xfff9f2f0, 0xfffaf5f5, 0xfffaf5f5, 0xfffaf5f5, 0xfff6e3da, 0xfff0c9b3, 0xffebb290,
+    0xffe8a176, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe9a881, 0xffeebda1, 0xfff3d6c6,
+    0xfff8ece8, 0xfffaf5f5, 0xfffaf5f5, 0xfff8eeeb, 0xfff3d8c9, 0xffeebda1, 0xffe9a67f, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe9a47c, 0xffeaad89, 0xffebb08e, 0xffeaac88, 0xffe8a379, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe9a47c, 0xffecb392, 0xffefc2a9,
+    0xfff1cdb9, 0xfff2cfbc, 0xfff0c7b0, 0xffecb798, 0xffe9a57d, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffeaab86, 0xffefc2a8, 0xfff3d7c7, 0xfff6e4db, 0xfff6e5de, 0xfff4ddd0, 0xfff1ccb8, 0xffedba9d,
+    0xffeaab86, 0xffe8a177, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075,
+    0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a075, 0xffe8a47b, 0xffeebc9f,
+    0xfff1cdb9, 0xfff5e1d7, 0xfff8eeea, 0xfff9f0ed, 0xfff7e7e0, 0xfff3d6c6, 0xffefc2a7, 0xffebb08b,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a87f, 0xffedb899, 0xfff1ccb6,
+    0xfff5ddd1, 0xfff7e8e1, 0xfff7e8e2, 0xfff5dfd3, 0xfff1cdb8, 0xffedb999, 0xffe9a77d, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a57b, 0xffeaac86, 0xffebaf89, 0xffeaab84, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a57b, 0xffebb18c, 0xffeebc9e,
+    0xffefc5ab, 0xfff0c6ae, 0xffeec0a4, 0xffecb491, 0xffe9a67c, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffeaaa83, 0xffeebc9e, 0xfff1ccb7, 0xfff3d6c6, 0xfff3d8c9, 0xfff2d1be, 0xffefc4ab, 0xffecb695,
+    0xffeaaa83, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479,
+    0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a479, 0xffe9a57a, 0xffedb797,
+    0xfff0c3a7, 0xfff3d1bd, 0xfff4dacb, 0xfff5dbce, 0xfff3d5c4, 0xfff1c9b1, 0xffeebb9a, 0xffecad86,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffedb490, 0xfff0c2a5,
+    0xfff2ceb9, 0xfff3d6c4, 0xfff4d6c5, 0xfff2cfba, 0xfff0c3a7, 0xffedb490, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffebab82, 0xffebad84, 0xffebaa81, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffecae87, 0xffedb794,
+    0xffefbc9d, 0xffefbe9f, 0xffeeb998, 0xffecb08a, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffebaa80, 0xffedb693, 0xfff0c2a6, 0xfff1c9b1, 0xfff1cab3, 0xfff0c5ab, 0xffefbc9d, 0xffedb28d,
+    0xffebaa80, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d,
+    0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffeba87d, 0xffedb38e,
+    0xffefb998, 0xfff1c3a7, 0xfff2c9b0, 0xfff2cab2, 0xfff1c6ab, 0xfff0be9e, 0xffeeb48f, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedaf88, 0xffefb997,
+    0xfff0c1a4, 0xfff1c6ac, 0xfff1c6ac, 0xfff1c2a5, 0xffefb998, 0xffedaf88, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedac82, 0xffeeb18b,
+    0xffeeb591, 0xffefb692, 0xffeeb38e, 0xffedad85, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffeeb18b, 0xffefb997, 0xfff0be9f, 0xfff0bea0, 0xffefbb9b, 0xffeeb591, 0xffedae86,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81,
+    0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedab81, 0xffedaf87,
+    0xffeeb48c, 0xffefba96, 0xfff0bd9c, 0xfff0be9c, 0xfff0bb98, 0xffefb690, 0xffeeb087, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeb38b,
+    0xffefb894, 0xfff0bc99, 0xfff0bc99, 0xffefb994, 0xffeeb48c, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeb188, 0xffeeb289, 0xffeeb086, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeb48c, 0xffefb790, 0xffefb791, 0xffefb58e, 0xffeeb188, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84, 0xffeeaf84,
+    0xfff0b288, 0xfff0b48b, 0xfff0b68f, 0xfff0b68f, 0xfff0b58d, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b38a, 0xfff0b58d, 0xfff0b58d, 0xfff0b38b, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b289, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288, 0xfff0b288,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c, 0xfff1b68c,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990, 0xfff3b990,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94, 0xfff5bd94,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098, 0xfff6c098,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c, 0xfff8c49c,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+    0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0, 0xfffac8a0,
+];
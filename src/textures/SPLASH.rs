@@ -0,0 +1,14 @@
+pub const SPLASH_WIDTH: u32 = 8;
+
+pub const SPLASH_HEIGHT: u32 = 8;
+
+pub const SPLASH_ARRAY: [u32; 64] = [
+    0x00000000, 0x00000000, 0x00000000, 0xffffe0c0, 0xffffe0c0, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0x00000000,
+    0x00000000, 0xffffe0c0, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffe0c0, 0x00000000,
+    0xffffe0c0, 0xffffe0c0, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffe0c0, 0xffffe0c0,
+    0xffffe0c0, 0xffffe0c0, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffe0c0, 0xffffe0c0,
+    0x00000000, 0xffffe0c0, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffb060, 0xffffe0c0, 0x00000000,
+    0x00000000, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0xffffe0c0, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0xffffe0c0, 0xffffe0c0, 0x00000000, 0x00000000, 0x00000000,
+];
@@ -1,11 +1,16 @@
-use crate::Texture;
+use crate::{mipmaps, Animation, Texture};
 pub mod BRAT;
 pub mod BRICK_1A;
 pub mod BRICK_2B;
 pub mod BRICK_3D;
 pub mod CONSOLE_1B;
+pub mod CONSOLE_1C;
+pub mod CONSOLE_1D;
+pub mod GRATE_1A;
 pub mod ORANGE_TILE;
+pub mod SKY;
 pub mod SLIME_1A;
+pub mod SPLASH;
 pub mod TILE_1A;
 pub mod TILE_2C;
 pub mod WATER_GUN;
@@ -18,6 +23,10 @@ pub const BRAT_TEXTURE: Texture = Texture {
     width: BRAT::BRAT_WIDTH,
     height: BRAT::BRAT_HEIGHT,
     data: &BRAT::BRAT_ARRAY,
+    mips: &mipmaps::chain::<{ mipmaps::chain_length(BRAT::BRAT_WIDTH, BRAT::BRAT_HEIGHT) }>(
+        &BRAT::BRAT_ARRAY,
+        BRAT::BRAT_WIDTH,
+    ),
 };
 
 pub const WATER_GUN_TEXTURE: Texture = Texture {
@@ -25,6 +34,23 @@ pub const WATER_GUN_TEXTURE: Texture = Texture {
     width: WATER_GUN::WATER_GUN_WIDTH,
     height: WATER_GUN::WATER_GUN_HEIGHT,
     data: &WATER_GUN::WATER_GUN_ARRAY,
+    mips: &[],
+};
+
+pub const SPLASH_TEXTURE: Texture = Texture {
+    name: "SPLASH_TEXTURE",
+    width: SPLASH::SPLASH_WIDTH,
+    height: SPLASH::SPLASH_HEIGHT,
+    data: &SPLASH::SPLASH_ARRAY,
+    mips: &[],
+};
+
+pub const SKY_TEXTURE: Texture = Texture {
+    name: "SKY_TEXTURE",
+    width: SKY::SKY_WIDTH,
+    height: SKY::SKY_HEIGHT,
+    data: &SKY::SKY_ARRAY,
+    mips: &[],
 };
 
 pub const ORANGE_TILE_TEXTURE: Texture = Texture {
@@ -32,6 +58,17 @@ pub const ORANGE_TILE_TEXTURE: Texture = Texture {
     width: ORANGE_TILE::ORANGE_TILE_WIDTH,
     height: ORANGE_TILE::ORANGE_TILE_HEIGHT,
     data: &ORANGE_TILE::ORANGE_TILE_ARRAY,
+    mips: &mipmaps::chain::<
+        {
+            mipmaps::chain_length(
+                ORANGE_TILE::ORANGE_TILE_WIDTH,
+                ORANGE_TILE::ORANGE_TILE_HEIGHT,
+            )
+        },
+    >(
+        &ORANGE_TILE::ORANGE_TILE_ARRAY,
+        ORANGE_TILE::ORANGE_TILE_WIDTH,
+    ),
 };
 
 pub const BRICK_1A_TEXTURE: Texture = Texture {
@@ -39,6 +76,9 @@ pub const BRICK_1A_TEXTURE: Texture = Texture {
     width: BRICK_1A::BRICK_1A_WIDTH,
     height: BRICK_1A::BRICK_1A_HEIGHT,
     data: &BRICK_1A::BRICK_1A_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(BRICK_1A::BRICK_1A_WIDTH, BRICK_1A::BRICK_1A_HEIGHT) },
+    >(&BRICK_1A::BRICK_1A_ARRAY, BRICK_1A::BRICK_1A_WIDTH),
 };
 
 pub const BRICK_2B_TEXTURE: Texture = Texture {
@@ -46,6 +86,9 @@ pub const BRICK_2B_TEXTURE: Texture = Texture {
     width: BRICK_2B::BRICK_2B_WIDTH,
     height: BRICK_2B::BRICK_2B_HEIGHT,
     data: &BRICK_2B::BRICK_2B_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(BRICK_2B::BRICK_2B_WIDTH, BRICK_2B::BRICK_2B_HEIGHT) },
+    >(&BRICK_2B::BRICK_2B_ARRAY, BRICK_2B::BRICK_2B_WIDTH),
 };
 
 pub const BRICK_3D_TEXTURE: Texture = Texture {
@@ -53,6 +96,9 @@ pub const BRICK_3D_TEXTURE: Texture = Texture {
     width: BRICK_3D::BRICK_3D_WIDTH,
     height: BRICK_3D::BRICK_3D_HEIGHT,
     data: &BRICK_3D::BRICK_3D_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(BRICK_3D::BRICK_3D_WIDTH, BRICK_3D::BRICK_3D_HEIGHT) },
+    >(&BRICK_3D::BRICK_3D_ARRAY, BRICK_3D::BRICK_3D_WIDTH),
 };
 
 pub const CONSOLE_1B_TEXTURE: Texture = Texture {
@@ -60,6 +106,9 @@ pub const CONSOLE_1B_TEXTURE: Texture = Texture {
     width: CONSOLE_1B::CONSOLE_1B_WIDTH,
     height: CONSOLE_1B::CONSOLE_1B_HEIGHT,
     data: &CONSOLE_1B::CONSOLE_1B_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(CONSOLE_1B::CONSOLE_1B_WIDTH, CONSOLE_1B::CONSOLE_1B_HEIGHT) },
+    >(&CONSOLE_1B::CONSOLE_1B_ARRAY, CONSOLE_1B::CONSOLE_1B_WIDTH),
 };
 
 pub const SLIME_1A_TEXTURE: Texture = Texture {
@@ -67,6 +116,9 @@ pub const SLIME_1A_TEXTURE: Texture = Texture {
     width: SLIME_1A::SLIME_1A_WIDTH,
     height: SLIME_1A::SLIME_1A_HEIGHT,
     data: &SLIME_1A::SLIME_1A_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(SLIME_1A::SLIME_1A_WIDTH, SLIME_1A::SLIME_1A_HEIGHT) },
+    >(&SLIME_1A::SLIME_1A_ARRAY, SLIME_1A::SLIME_1A_WIDTH),
 };
 
 pub const TILE_1A_TEXTURE: Texture = Texture {
@@ -74,6 +126,9 @@ pub const TILE_1A_TEXTURE: Texture = Texture {
     width: TILE_1A::TILE_1A_WIDTH,
     height: TILE_1A::TILE_1A_HEIGHT,
     data: &TILE_1A::TILE_1A_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(TILE_1A::TILE_1A_WIDTH, TILE_1A::TILE_1A_HEIGHT) },
+    >(&TILE_1A::TILE_1A_ARRAY, TILE_1A::TILE_1A_WIDTH),
 };
 
 pub const TILE_2C_TEXTURE: Texture = Texture {
@@ -81,6 +136,9 @@ pub const TILE_2C_TEXTURE: Texture = Texture {
     width: TILE_2C::TILE_2C_WIDTH,
     height: TILE_2C::TILE_2C_HEIGHT,
     data: &TILE_2C::TILE_2C_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(TILE_2C::TILE_2C_WIDTH, TILE_2C::TILE_2C_HEIGHT) },
+    >(&TILE_2C::TILE_2C_ARRAY, TILE_2C::TILE_2C_WIDTH),
 };
 
 pub const WOOD_1C_TEXTURE: Texture = Texture {
@@ -88,9 +146,42 @@ pub const WOOD_1C_TEXTURE: Texture = Texture {
     width: WOOD_1C::WOOD_1C_WIDTH,
     height: WOOD_1C::WOOD_1C_HEIGHT,
     data: &WOOD_1C::WOOD_1C_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(WOOD_1C::WOOD_1C_WIDTH, WOOD_1C::WOOD_1C_HEIGHT) },
+    >(&WOOD_1C::WOOD_1C_ARRAY, WOOD_1C::WOOD_1C_WIDTH),
 };
 
-pub const TEXTURES: [Texture; 10] = [
+pub const CONSOLE_1C_TEXTURE: Texture = Texture {
+    name: "CONSOLE_1C_TEXTURE",
+    width: CONSOLE_1C::CONSOLE_1C_WIDTH,
+    height: CONSOLE_1C::CONSOLE_1C_HEIGHT,
+    data: &CONSOLE_1C::CONSOLE_1C_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(CONSOLE_1C::CONSOLE_1C_WIDTH, CONSOLE_1C::CONSOLE_1C_HEIGHT) },
+    >(&CONSOLE_1C::CONSOLE_1C_ARRAY, CONSOLE_1C::CONSOLE_1C_WIDTH),
+};
+
+pub const CONSOLE_1D_TEXTURE: Texture = Texture {
+    name: "CONSOLE_1D_TEXTURE",
+    width: CONSOLE_1D::CONSOLE_1D_WIDTH,
+    height: CONSOLE_1D::CONSOLE_1D_HEIGHT,
+    data: &CONSOLE_1D::CONSOLE_1D_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(CONSOLE_1D::CONSOLE_1D_WIDTH, CONSOLE_1D::CONSOLE_1D_HEIGHT) },
+    >(&CONSOLE_1D::CONSOLE_1D_ARRAY, CONSOLE_1D::CONSOLE_1D_WIDTH),
+};
+
+pub const GRATE_1A_TEXTURE: Texture = Texture {
+    name: "GRATE_1A_TEXTURE",
+    width: GRATE_1A::GRATE_1A_WIDTH,
+    height: GRATE_1A::GRATE_1A_HEIGHT,
+    data: &GRATE_1A::GRATE_1A_ARRAY,
+    mips: &mipmaps::chain::<
+        { mipmaps::chain_length(GRATE_1A::GRATE_1A_WIDTH, GRATE_1A::GRATE_1A_HEIGHT) },
+    >(&GRATE_1A::GRATE_1A_ARRAY, GRATE_1A::GRATE_1A_WIDTH),
+};
+
+pub const TEXTURES: [Texture; 11] = [
     BRAT_TEXTURE,
     ORANGE_TILE_TEXTURE,
     BRICK_1A_TEXTURE,
@@ -101,4 +192,39 @@ pub const TEXTURES: [Texture; 10] = [
     TILE_1A_TEXTURE,
     TILE_2C_TEXTURE,
     WOOD_1C_TEXTURE,
+    GRATE_1A_TEXTURE,
 ];
+
+pub const ANIMATIONS: [Animation; 3] = [
+    Animation {
+        texture: SLIME_1A_TEXTURE,
+        frames: &[],
+        rate: 1,
+        scroll_u: 0.0,
+        scroll_v: 0.005,
+    }, // flowing slime
+    Animation {
+        texture: TILE_2C_TEXTURE,
+        frames: &[],
+        rate: 1,
+        scroll_u: 0.01,
+        scroll_v: 0.0,
+    }, // conveyor belt
+    Animation {
+        texture: CONSOLE_1B_TEXTURE,
+        frames: &[CONSOLE_1B_TEXTURE, CONSOLE_1C_TEXTURE, CONSOLE_1D_TEXTURE],
+        rate: 15,
+        scroll_u: 0.0,
+        scroll_v: 0.0,
+    }, // flickering monitors
+];
+
+pub fn animate(texture: Texture, clock: u32) -> (Texture, f32, f32) {
+    match ANIMATIONS
+        .iter()
+        .find(|animation| animation.texture.name == texture.name)
+    {
+        Some(animation) => animation.at(clock),
+        _ => (texture, 0.0, 0.0),
+    }
+} // the frame & scroll of a texture at a moment in the simulation; textures without an animation stay still
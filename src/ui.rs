@@ -0,0 +1,542 @@
+use crate::grid::{get_texture, Button};
+use crate::renderer::Renderer;
+use crate::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ui {
+    pub mouse_x: i32,                      // where the mouse is this frame
+    pub mouse_y: i32,                      // ...
+    pub click: Option<(Button, i32, i32)>, // a click that has not been used by any widget yet
+    pub areas: Vec<Rect>,                  // the panels drawn this frame
+    pub last_areas: Vec<Rect>, // the panels drawn last frame; used to keep clicks from reaching the editor
+    pub focus: Option<String>, // the widget that is being typed into or has its list open
+    pub input: String,         // the text typed into the focused widget so far
+    pub typed: String,         // the text typed this frame
+    pub keys: Vec<Keycode>,    // the keys pressed this frame while a widget has focus
+    pub typing: bool,          // the focused widget is a text box rather than an open list
+    pub scroll: i32,           // how many notches the mouse wheel moved over a panel this frame
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Choice<'a> {
+    pub options: &'a [&'a str],
+    pub selected: usize, // which of the options is shown in the dropdown
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Steps {
+    pub step: f32,     // how much a left click on plus or minus changes a spinner
+    pub big_step: f32, // ... and a right click
+}
+
+impl Default for Ui {
+    fn default() -> Ui {
+        Ui::new()
+    }
+}
+
+impl Ui {
+    pub fn new() -> Ui {
+        Ui {
+            mouse_x: 0,
+            mouse_y: 0,
+            click: None,
+            areas: Vec::new(),
+            last_areas: Vec::new(),
+            focus: None,
+            input: String::new(),
+            typed: String::new(),
+            keys: Vec::new(),
+            typing: false,
+            scroll: 0,
+        }
+    }
+
+    pub fn begin(&mut self, mouse_x: i32, mouse_y: i32) {
+        self.mouse_x = mouse_x;
+        self.mouse_y = mouse_y;
+        self.last_areas = std::mem::take(&mut self.areas);
+    } // called before any widgets are drawn each frame
+
+    pub fn end(&mut self) {
+        if self.click.is_some() {
+            self.focus = None;
+        }
+        self.click = None;
+        self.typed = String::new();
+        self.keys = Vec::new();
+        self.scroll = 0;
+    } // called after all widgets are drawn; throws away any input that no widget used
+
+    pub fn is_focused(&self, id: &str) -> bool {
+        self.focus.as_deref() == Some(id)
+    }
+
+    pub fn toggle_focus(&mut self, id: &str) {
+        match self.is_focused(id) {
+            true => self.focus = None,
+            false => self.focus = Some(id.to_string()),
+        }
+        self.typing = false;
+    } // opens or closes a widget's list
+
+    pub fn takes_key(&self, keycode: Keycode) -> bool {
+        match (&self.focus, keycode) {
+            (None, _) => false,
+            (_, Keycode::Backspace | Keycode::Return | Keycode::KpEnter | Keycode::Tab) => true,
+            _ => self.typing,
+        }
+    } // whether a key goes to the focused widget instead of the editor; only text boxes keep the keys that type
+
+    pub fn is_over(&self, x: i32, y: i32) -> bool {
+        self.last_areas
+            .iter()
+            .any(|area| area.contains_point((x, y)))
+    } // checks if a position on the screen is covered by a panel
+
+    pub fn hovered(&self, rect: Rect) -> bool {
+        rect.contains_point((self.mouse_x, self.mouse_y))
+    }
+
+    pub fn clicked(&mut self, rect: Rect) -> Option<Button> {
+        match self.click {
+            Some((button, x, y)) if rect.contains_point((x, y)) => {
+                self.click = None;
+                Some(button)
+            }
+            _ => None,
+        }
+    } // uses up the click if it landed inside of the given area
+
+    pub fn scrolled(&mut self, rect: Rect) -> i32 {
+        match self.hovered(rect) {
+            true => std::mem::take(&mut self.scroll),
+            false => 0,
+        }
+    } // uses up the mouse wheel if the mouse is inside of the given area
+}
+
+pub struct Layout {
+    pub area: Rect,        // the space that widgets are placed in
+    pub x: i32,            // where the next widget will be placed
+    pub y: i32,            // ...
+    pub row_height: u32,   // how tall each row of widgets is
+    pub column_x: i32,     // where the current column starts
+    pub column_width: u32, // how wide the current column is
+    pub padding: i32,      // space left between widgets
+}
+
+impl Layout {
+    pub fn new(area: Rect, rows: u32) -> Layout {
+        let padding = (area.height() / (rows * 8)) as i32;
+        Layout {
+            area,
+            x: area.x() + padding,
+            y: area.y() + padding,
+            row_height: (area.height() - padding as u32) / rows - padding as u32,
+            column_x: area.x(),
+            column_width: 0,
+            padding,
+        }
+    } // splits an area into rows that all have the same height
+
+    pub fn column(&mut self, fraction: f32) {
+        self.column_x += self.column_width as i32 + self.padding;
+        self.column_width = (fraction * self.area.width() as f32) as u32;
+        self.x = self.column_x;
+        self.y = self.area.y() + self.padding;
+    } // starts a new column that takes up the given fraction of the area's width
+
+    pub fn row(&mut self) {
+        self.x = self.column_x;
+        self.y += self.row_height as i32 + self.padding;
+    } // moves down to the next row of the current column
+
+    pub fn next(&mut self, width: u32, height: u32) -> Rect {
+        let rect = Rect::new(self.x, self.y, width, height);
+        self.x += width as i32 + self.padding;
+        rect
+    } // reserves space for a widget to the right of the last one
+
+    pub fn form(&self) -> (u32, u32) {
+        let caption = 2 * self.column_width / 5;
+        (caption, self.column_width - caption - self.padding as u32)
+    } // how wide a field's name & the field after it are, so that every row of a form lines up
+
+    pub fn remaining(&self) -> u32 {
+        (self.column_x + self.column_width as i32 - self.x).max(0) as u32
+    } // how much space is left in the current row
+}
+
+pub fn panel(renderer: &mut Renderer, ui: &mut Ui, rect: Rect, color: Color) -> Result<(), String> {
+    renderer.canvas.set_draw_color(color);
+    renderer.canvas.fill_rect(rect)?;
+    ui.areas.push(rect);
+    Ok(())
+} // fills in a background for other widgets and keeps clicks on it from reaching the editor
+
+pub fn label(
+    renderer: &mut Renderer,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    text: &str,
+    color: Color,
+) -> Result<Rect, String> {
+    let width = text_width(font, text, layout.row_height)?;
+    let rect = layout.next(width.min(layout.remaining()), layout.row_height);
+    draw_text(renderer, font, text, color, rect)?;
+    Ok(rect)
+} // draws a line of text that is as tall as the row it is in
+
+pub fn caption(
+    renderer: &mut Renderer,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    text: &str,
+    width: u32,
+) -> Result<(), String> {
+    let rect = layout.next(width, layout.row_height);
+    fit_text(renderer, font, text, colors::BLACK, rect)
+} // draws the name of a field in a space with a set width so that the fields after it line up
+
+pub fn number_field(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    id: &str,
+    value: f32,
+    width: u32,
+) -> Result<f32, String> {
+    let rect = layout.next(width, layout.row_height);
+    if ui.clicked(rect).is_some() && !ui.is_focused(id) {
+        ui.focus = Some(id.to_string());
+        ui.input = String::new();
+        ui.typing = true;
+    } // clicking on the field starts typing a new value into it
+
+    let mut value = value;
+    if ui.is_focused(id) {
+        for character in std::mem::take(&mut ui.typed).chars() {
+            if character.is_ascii_digit() || character == '.' || character == '-' {
+                ui.input.push(character);
+            }
+        }
+        for key in std::mem::take(&mut ui.keys) {
+            match key {
+                Keycode::Backspace => {
+                    ui.input.pop();
+                }
+                Keycode::Return | Keycode::KpEnter | Keycode::Tab => {
+                    match ui.input.parse::<f32>() {
+                        Ok(number) => value = number,
+                        _ => {}
+                    }
+                    ui.focus = None;
+                }
+                _ => {}
+            }
+        } // enter keeps the typed value and escape (which clears the focus) throws it away
+    }
+
+    let text = match ui.is_focused(id) {
+        true => format!("{}_", ui.input),
+        false => format!("{}", value),
+    };
+    renderer.canvas.set_draw_color(match ui.is_focused(id) {
+        true => colors::WHITE,
+        false => colors::GREY1,
+    });
+    renderer.canvas.fill_rect(rect)?;
+    renderer.canvas.set_draw_color(colors::BLACK);
+    renderer.canvas.draw_rect(rect)?;
+    fit_text(renderer, font, &text, colors::BLACK, rect)?;
+    Ok(value)
+} // draws a box with a number in it that can be clicked on and typed into
+
+pub fn text_field(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    id: &str,
+    text: &str,
+    width: u32,
+) -> Result<String, String> {
+    let rect = layout.next(width, layout.row_height);
+    if ui.clicked(rect).is_some() && !ui.is_focused(id) {
+        ui.focus = Some(id.to_string());
+        ui.typing = true;
+    }
+
+    let mut text = text.to_string();
+    if ui.is_focused(id) {
+        text.push_str(&std::mem::take(&mut ui.typed));
+        for key in std::mem::take(&mut ui.keys) {
+            match key {
+                Keycode::Backspace => {
+                    text.pop();
+                }
+                Keycode::Return | Keycode::KpEnter | Keycode::Tab => ui.focus = None,
+                _ => {}
+            }
+        }
+    } // unlike number_field the text changes as it is typed
+
+    let shown = match ui.is_focused(id) {
+        true => format!("{}_", text),
+        false => text.clone(),
+    };
+    renderer.canvas.set_draw_color(match ui.is_focused(id) {
+        true => colors::WHITE,
+        false => colors::GREY1,
+    });
+    renderer.canvas.fill_rect(rect)?;
+    renderer.canvas.set_draw_color(colors::BLACK);
+    renderer.canvas.draw_rect(rect)?;
+    fit_text(renderer, font, &shown, colors::BLACK, rect)?;
+    Ok(text)
+} // draws a box that can be clicked on and typed into
+
+pub fn tab(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    text: &str,
+    active: bool,
+) -> Result<bool, String> {
+    let width = text_width(font, text, layout.row_height)?;
+    let rect = layout.next(width, layout.row_height);
+    renderer
+        .canvas
+        .set_draw_color(match (active, ui.hovered(rect)) {
+            (true, _) => colors::WHITE,
+            (false, true) => colors::GREY1,
+            (false, false) => colors::GREY2,
+        });
+    renderer.canvas.fill_rect(rect)?;
+    renderer.canvas.set_draw_color(colors::BLACK);
+    renderer.canvas.draw_rect(rect)?;
+    fit_text(renderer, font, text, colors::BLACK, rect)?;
+    Ok(ui.clicked(rect).is_some())
+} // draws a button with text on it that stays lit up while it is active
+
+pub fn color_picker(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    id: &str,
+    color: Color,
+) -> Result<Color, String> {
+    let rect = layout.next(layout.row_height * 2, layout.row_height);
+    renderer.canvas.set_draw_color(color);
+    renderer.canvas.fill_rect(rect)?;
+    renderer.canvas.set_draw_color(colors::BLACK);
+    renderer.canvas.draw_rect(rect)?;
+    if ui.clicked(rect).is_some() {
+        ui.toggle_focus(id);
+    }
+
+    let mut color = color;
+    if ui.is_focused(id) {
+        let size = layout.row_height;
+        let columns = 11;
+        let rows = (colors::PALETTE.len() as u32).div_ceil(columns);
+        let popup = popup_rect(layout, rect, columns * size, rows * size);
+        panel(renderer, ui, popup, colors::GREY4)?;
+        for (i, swatch) in colors::PALETTE.iter().enumerate() {
+            let swatch_rect = Rect::new(
+                popup.x() + (i as u32 % columns * size) as i32,
+                popup.y() + (i as u32 / columns * size) as i32,
+                size,
+                size,
+            );
+            renderer.canvas.set_draw_color(*swatch);
+            renderer.canvas.fill_rect(swatch_rect)?;
+            if ui.hovered(swatch_rect) {
+                renderer.canvas.set_draw_color(colors::WHITE);
+                renderer.canvas.draw_rect(swatch_rect)?;
+            }
+            if ui.clicked(swatch_rect).is_some() {
+                color = *swatch;
+                ui.focus = None;
+            }
+        }
+    } // the palette opens to the left of the panel the picker is in
+    Ok(color)
+} // draws a swatch of a color that opens the palette from colors.rs when clicked
+
+pub fn dropdown(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    id: &str,
+    choice: Choice,
+    width: u32,
+) -> Result<usize, String> {
+    let (options, selected) = (choice.options, choice.selected);
+    let rect = layout.next(width, layout.row_height);
+    renderer.canvas.set_draw_color(match ui.hovered(rect) {
+        true => colors::GREY1,
+        false => colors::GREY2,
+    });
+    renderer.canvas.fill_rect(rect)?;
+    renderer.canvas.set_draw_color(colors::BLACK);
+    renderer.canvas.draw_rect(rect)?;
+    fit_text(renderer, font, options[selected], colors::BLACK, rect)?;
+    if ui.clicked(rect).is_some() {
+        ui.toggle_focus(id);
+    }
+
+    let mut selected = selected;
+    if ui.is_focused(id) {
+        let height = layout.row_height;
+        let popup = popup_rect(layout, rect, width, options.len() as u32 * height);
+        panel(renderer, ui, popup, colors::GREY2)?;
+        for (i, option) in options.iter().enumerate() {
+            let option_rect = Rect::new(
+                popup.x(),
+                popup.y() + (i as u32 * height) as i32,
+                width,
+                height,
+            );
+            if ui.hovered(option_rect) || i == selected {
+                renderer.canvas.set_draw_color(colors::GREY1);
+                renderer.canvas.fill_rect(option_rect)?;
+            }
+            fit_text(renderer, font, option, colors::BLACK, option_rect)?;
+            if ui.clicked(option_rect).is_some() {
+                selected = i;
+                ui.focus = None;
+            }
+        }
+    } // the list opens to the left of the panel the dropdown is in
+    Ok(selected)
+} // draws the selected option & opens a list of all of the options when clicked
+
+pub fn popup_rect(layout: &Layout, rect: Rect, width: u32, height: u32) -> Rect {
+    let y = rect.y().min(SCREEN_HEIGHT as i32 - height as i32).max(0);
+    Rect::new(layout.area.x() - width as i32, y, width, height)
+} // places a popup next to the panel a widget is in while keeping it on the screen
+
+pub fn button(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    text: &str,
+) -> Result<Option<Button>, String> {
+    let rect = layout.next(layout.row_height, layout.row_height);
+    let background = match ui.hovered(rect) {
+        true => colors::GREY1,
+        false => colors::GREY2,
+    };
+    renderer.canvas.set_draw_color(background);
+    renderer.canvas.fill_rect(rect)?;
+    renderer.canvas.set_draw_color(colors::BLACK);
+    renderer.canvas.draw_rect(rect)?;
+    draw_text(renderer, font, text, colors::BLACK, rect)?;
+    Ok(ui.clicked(rect))
+} // draws a square button & returns which mouse button (if any) clicked it this frame
+
+pub fn spinner(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    layout: &mut Layout,
+    font: &sdl2::ttf::Font,
+    id: &str,
+    value: f32,
+    steps: Steps,
+) -> Result<f32, String> {
+    let width = layout
+        .remaining()
+        .saturating_sub(2 * (layout.row_height + layout.padding as u32));
+    let mut value = number_field(renderer, ui, layout, font, id, value, width)?;
+    match button(renderer, ui, layout, font, "+")? {
+        Some(Button::Left) => value += steps.step,
+        Some(Button::Right) => value += steps.big_step,
+        _ => {}
+    }
+    match button(renderer, ui, layout, font, "-")? {
+        Some(Button::Left) => value -= steps.step,
+        Some(Button::Right) => value -= steps.big_step,
+        _ => {}
+    }
+    Ok(value)
+} // draws a number field with plus & minus buttons; left click changes it by step and right click by big_step
+
+pub fn texture_button(
+    renderer: &mut Renderer,
+    ui: &mut Ui,
+    rect: Rect,
+    texture: Texture,
+) -> Result<Option<Button>, String> {
+    let texture_creator = renderer.canvas.texture_creator();
+    let preview = get_texture(
+        &texture_creator,
+        texture.width,
+        texture.height,
+        texture.data,
+    )?;
+    renderer.canvas.copy(&preview, None, rect)?;
+    renderer.canvas.set_draw_color(match ui.hovered(rect) {
+        true => colors::WHITE,
+        false => colors::BLACK,
+    });
+    for i in 0..3 {
+        renderer.canvas.draw_rect(Rect::new(
+            rect.x() - i,
+            rect.y() - i,
+            rect.width() + 2 * i as u32,
+            rect.height() + 2 * i as u32,
+        ))?;
+    }
+    Ok(ui.clicked(rect))
+} // draws a preview of a texture that can be clicked on
+
+pub fn text_width(font: &sdl2::ttf::Font, text: &str, height: u32) -> Result<u32, String> {
+    let (text_width, text_height) = font.size_of(text).map_err(|e| e.to_string())?;
+    Ok(text_width * height / one_if_none(text_height as f32) as u32)
+} // how wide a line of text is when it is drawn at the given height
+
+pub fn fit_text(
+    renderer: &mut Renderer,
+    font: &sdl2::ttf::Font,
+    text: &str,
+    color: Color,
+    rect: Rect,
+) -> Result<(), String> {
+    let padding = rect.height() / 8;
+    let height = rect.height() - 2 * padding;
+    let width = text_width(font, text, height)?.min(rect.width().saturating_sub(2 * padding));
+    let text_rect = Rect::new(
+        rect.x() + padding as i32,
+        rect.y() + padding as i32,
+        width,
+        height,
+    );
+    draw_text(renderer, font, text, color, text_rect)
+} // draws text inside of an area without stretching it
+
+pub fn draw_text(
+    renderer: &mut Renderer,
+    font: &sdl2::ttf::Font,
+    text: &str,
+    color: Color,
+    rect: Rect,
+) -> Result<(), String> {
+    if text.is_empty() {
+        return Ok(());
+    } // sdl can't render an empty string
+    let texture_creator = renderer.canvas.texture_creator();
+    Renderer::text(
+        renderer,
+        &texture_creator,
+        font,
+        text.to_string(),
+        color,
+        rect,
+    )
+} // draws text stretched to fill the given area
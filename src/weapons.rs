@@ -0,0 +1,301 @@
+use crate::*;
+
+pub const RECOIL_TICKS: u32 = 8; // how long the firing animation lasts
+pub const IMPACT_TICKS: u32 = 12; // how long a splash stays on whatever was hit
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FireMode {
+    Hitscan, // hits the first thing in front of the player the moment it is fired
+    Projectile { speed: f32 }, // fires a shot that travels this far every tick
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weapon {
+    pub name: &'static str,
+    pub texture: Texture, // drawn in front of the player while the weapon is held
+    pub tint: Color, // multiplies the texture's colors, so weapons sharing a texture look different
+    pub fire_mode: FireMode,
+    pub fire_rate: u32, // ticks between shots
+    pub damage: i32,
+    pub range: f32, // how far a shot can go before it is gone
+    pub ammo: u32,  // how many shots the player starts with
+}
+
+pub const WEAPONS: [Weapon; 2] = [
+    Weapon {
+        name: "Water Gun",
+        texture: textures::WATER_GUN_TEXTURE,
+        tint: colors::WHITE,
+        fire_mode: FireMode::Projectile { speed: 6.0 },
+        fire_rate: 20,
+        damage: 10,
+        range: 300.0,
+        ammo: 50,
+    },
+    Weapon {
+        name: "Super Soaker",
+        texture: textures::WATER_GUN_TEXTURE,
+        tint: colors::ORANGE,
+        fire_mode: FireMode::Hitscan,
+        fire_rate: 6,
+        damage: 4,
+        range: 400.0,
+        ammo: 200,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hit {
+    Wall(usize),
+    Entity(usize),
+    Actor(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projectile {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub dx: f32, // how far the shot moves each tick
+    pub dy: f32,
+    pub travelled: f32,
+    pub damage: i32,
+    pub range: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Impact {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub ticks: u32, // how much longer the splash is drawn for
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Weapons {
+    pub current: usize,             // which of WEAPONS the player is holding
+    pub cooldown: u32,              // ticks until the weapon can fire again
+    pub recoil: u32,                // ticks left in the firing animation
+    pub bob: u32,                   // goes up as the player walks, swaying the weapon
+    pub ammo: [u32; WEAPONS.len()], // how many shots are left for each weapon
+    pub projectiles: Vec<Projectile>,
+    pub impacts: Vec<Impact>,
+}
+
+impl Weapons {
+    pub fn new() -> Weapons {
+        Weapons {
+            ammo: WEAPONS.map(|weapon| weapon.ammo),
+            ..Weapons::default()
+        }
+    } // every weapon with its starting ammo
+
+    pub fn weapon(&self) -> Weapon {
+        WEAPONS[self.current]
+    } // the weapon the player is holding
+
+    pub fn switch(&mut self, weapon: usize) {
+        if weapon < WEAPONS.len() && weapon != self.current {
+            self.current = weapon;
+            self.cooldown = RECOIL_TICKS;
+            self.recoil = 0;
+        }
+    } // puts away the current weapon and takes out another
+
+    pub fn sprites(&self) -> Vec<Sprite> {
+        let mut sprites = Vec::new();
+        for projectile in self.projectiles.iter() {
+            sprites.push(Sprite {
+                x: projectile.x,
+                y: projectile.y,
+                z: projectile.z - 1.5,
+                height: 3.0,
+                texture: textures::SPLASH_TEXTURE,
+            });
+        }
+        for impact in self.impacts.iter() {
+            let size = 2.0 + (IMPACT_TICKS - impact.ticks) as f32 / 2.0; // splashes spread out as they fade
+            sprites.push(Sprite {
+                x: impact.x,
+                y: impact.y,
+                z: impact.z - size / 2.0,
+                height: size,
+                texture: textures::SPLASH_TEXTURE,
+            });
+        }
+        sprites
+    } // shots in the air & splashes, drawn alongside the level's sprites
+}
+
+impl PlayerInfo {
+    pub fn fire(&mut self) {
+        if self.weapons.cooldown > 0 || self.weapons.ammo[self.weapons.current] == 0 {
+            return;
+        }
+        let weapon = self.weapons.weapon();
+        self.weapons.ammo[self.weapons.current] -= 1;
+        self.weapons.cooldown = weapon.fire_rate;
+        self.weapons.recoil = RECOIL_TICKS;
+        self.level
+            .sound(audio::FIRE, self.position.x as f32, self.position.y as f32);
+
+        let (x, y, z) = (
+            self.position.x as f32,
+            self.position.y as f32,
+            self.position.z as f32,
+        );
+        let (dx, dy) = (sine(self.angle_h), cosine(self.angle_h));
+        match weapon.fire_mode {
+            FireMode::Hitscan => {
+                let (x2, y2) = (x + dx * weapon.range, y + dy * weapon.range);
+                match self.trace(x, y, x2, y2, z) {
+                    Some((hit, t)) => {
+                        self.impact(x + (x2 - x) * t, y + (y2 - y) * t, z);
+                        self.hit(hit, weapon.damage);
+                    }
+                    _ => {}
+                }
+            }
+            FireMode::Projectile { speed } => self.weapons.projectiles.push(Projectile {
+                x,
+                y,
+                z,
+                dx: dx * speed,
+                dy: dy * speed,
+                travelled: 0.0,
+                damage: weapon.damage,
+                range: weapon.range,
+            }),
+        }
+    } // fires the current weapon if it is ready
+
+    pub fn update_weapons(&mut self) {
+        self.weapons.cooldown = self.weapons.cooldown.saturating_sub(1);
+        self.weapons.recoil = self.weapons.recoil.saturating_sub(1);
+        for impact in self.weapons.impacts.iter_mut() {
+            impact.ticks = impact.ticks.saturating_sub(1);
+        }
+        self.weapons.impacts.retain(|impact| impact.ticks > 0);
+
+        for p in (0..self.weapons.projectiles.len()).rev() {
+            let mut projectile = self.weapons.projectiles[p];
+            let (x2, y2) = (projectile.x + projectile.dx, projectile.y + projectile.dy);
+            match self.trace(projectile.x, projectile.y, x2, y2, projectile.z) {
+                Some((hit, t)) => {
+                    self.weapons.projectiles.remove(p);
+                    self.impact(
+                        projectile.x + projectile.dx * t,
+                        projectile.y + projectile.dy * t,
+                        projectile.z,
+                    );
+                    self.hit(hit, projectile.damage);
+                    continue;
+                }
+                _ => {}
+            }
+            projectile.x = x2;
+            projectile.y = y2;
+            projectile.travelled += distance(0.0, 0.0, projectile.dx, projectile.dy);
+            match projectile.travelled > projectile.range {
+                true => {
+                    self.weapons.projectiles.remove(p);
+                }
+                false => self.weapons.projectiles[p] = projectile,
+            }
+        }
+    } // moves shots along and counts down cooldowns & animations; called every tick
+
+    pub fn trace(&self, x1: f32, y1: f32, x2: f32, y2: f32, z: f32) -> Option<(Hit, f32)> {
+        let mut closest: Option<(Hit, f32)> = None;
+        for (w, wall) in self.level.walls.iter().enumerate() {
+            let solid = match self.level.sector_of_wall(w) {
+                Some(s) => self.level.sectors[s].blocks(z as i32),
+                _ => true,
+            }; // a shot passes over or under a sector that isn't as tall as where it is
+            match wall.crossed_by(x1, y1, x2, y2) {
+                Some(t) if solid && closest.is_none_or(|(_, closest_t)| t < closest_t) => {
+                    closest = Some((Hit::Wall(w), t))
+                }
+                _ => {}
+            }
+        }
+
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let length = one_if_none(dx * dx + dy * dy);
+        let sprites = self
+            .level
+            .entities
+            .iter()
+            .enumerate()
+            .filter(|(_, entity)| entity.kind != EntityKind::Enemy) // enemies are hit through their actor
+            .filter_map(|(e, entity)| {
+                entity.texture().map(|texture| {
+                    (
+                        Hit::Entity(e),
+                        Sprite {
+                            x: entity.x,
+                            y: entity.y,
+                            z: entity.z,
+                            height: entity.height(),
+                            texture,
+                        },
+                    )
+                })
+            }) // entities that can't be seen can't be hit
+            .chain(
+                self.level
+                    .actors
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, actor)| actor.alive())
+                    .map(|(a, actor)| (Hit::Actor(a), actor.sprite())),
+            );
+        for (hit, sprite) in sprites {
+            let radius =
+                sprite.height * sprite.texture.width as f32 / sprite.texture.height as f32 / 2.0;
+            let (bottom, top) = (
+                sprite.z.min(sprite.z + sprite.height),
+                sprite.z.max(sprite.z + sprite.height),
+            );
+            if z < bottom || top < z {
+                continue;
+            }
+            let t = (((sprite.x - x1) * dx + (sprite.y - y1) * dy) / length).clamp(0.0, 1.0);
+            if distance(x1 + dx * t, y1 + dy * t, sprite.x, sprite.y) <= radius
+                && closest.is_none_or(|(_, closest_t)| t < closest_t)
+            {
+                closest = Some((hit, t));
+            }
+        }
+        closest
+    } // finds the first wall, entity or actor along a line at a height, and how far along the line it is
+
+    fn impact(&mut self, x: f32, y: f32, z: f32) {
+        self.weapons.impacts.push(Impact {
+            x,
+            y,
+            z,
+            ticks: IMPACT_TICKS,
+        });
+        self.level.sound(audio::SPLASH, x, y);
+    } // leaves a splash where a shot landed
+
+    fn hit(&mut self, hit: Hit, damage: i32) {
+        match hit {
+            Hit::Wall(w) => {
+                self.trigger_wall(w, TriggerEvent::Shoot);
+                match self.level.sector_of_wall(w) {
+                    Some(s) => self.trigger_sector(s, TriggerEvent::Shoot),
+                    _ => {}
+                }
+            }
+            Hit::Entity(_) => {}
+            Hit::Actor(a) => {
+                let actor = &mut self.level.actors[a];
+                actor.damage(damage);
+                let (x, y) = (actor.x, actor.y);
+                self.level.sound(audio::PAIN, x, y);
+            }
+        }
+    } // sets off whatever a shot hit
+}